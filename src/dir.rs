@@ -0,0 +1,88 @@
+//! # compass directions
+/// one of the eight compass directions
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// all eight directions, clockwise from north
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+    /// the four cardinal directions, clockwise from north
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// `(row, col)` offset of one step in this direction
+    pub const fn delta(self) -> (isize, isize) {
+        match self {
+            Direction::North => (-1, 0),
+            Direction::NorthEast => (-1, 1),
+            Direction::East => (0, 1),
+            Direction::SouthEast => (1, 1),
+            Direction::South => (1, 0),
+            Direction::SouthWest => (1, -1),
+            Direction::West => (0, -1),
+            Direction::NorthWest => (-1, -1),
+        }
+    }
+    /// the direction pointing the other way
+    pub const fn opposite(self) -> Self {
+        self.rotate(4)
+    }
+    /// the direction 90 degrees counterclockwise
+    pub const fn turn_left(self) -> Self {
+        self.rotate(6)
+    }
+    /// the direction 90 degrees clockwise
+    pub const fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+    /// the direction `eighths` eighth-turns clockwise
+    const fn rotate(self, eighths: usize) -> Self {
+        Self::ALL[(self as usize + eighths) % 8]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_opposite() {
+        for dir in Direction::ALL {
+            assert_eq!(dir, dir.opposite().opposite());
+            let (d_row, d_col) = dir.delta();
+            assert_eq!((-d_row, -d_col), dir.opposite().delta());
+        }
+        assert_eq!(Direction::SouthWest, Direction::NorthEast.opposite());
+    }
+    #[test]
+    fn test_turn() {
+        for dir in Direction::ALL {
+            assert_eq!(dir, dir.turn_left().turn_right());
+            assert_eq!(dir.opposite(), dir.turn_right().turn_right());
+        }
+        assert_eq!(Direction::East, Direction::North.turn_right());
+        assert_eq!(Direction::South, Direction::East.turn_right());
+        assert_eq!(Direction::West, Direction::North.turn_left());
+    }
+}
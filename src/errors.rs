@@ -7,6 +7,12 @@ pub enum VError {
     /// incorrect dimensions
     #[error("Size mismatch error: expected {expected:?}, got {actual:?}")]
     SizingError { expected: usize, actual: usize },
+    /// malformed input
+    #[error("Parse error: {0}")]
+    ParseError(String),
+    /// index out of bounds
+    #[error("Index out of bounds")]
+    IndexError,
 }
 
 impl VError {
@@ -1,7 +1,10 @@
 //! # custom index types
+use crate::dir::Direction;
 use std::cmp::Ordering;
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawIx2"))]
 pub struct BoundedIx2<const N_ROWS: usize, const N_COLS: usize> {
     /// y-coordinate
     row_ix: usize,
@@ -9,6 +12,23 @@ pub struct BoundedIx2<const N_ROWS: usize, const N_COLS: usize> {
     col_ix: usize,
 }
 
+/// unvalidated deserialized index
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawIx2 {
+    row_ix: usize,
+    col_ix: usize,
+}
+
+#[cfg(feature = "serde")]
+impl<const N_ROWS: usize, const N_COLS: usize> TryFrom<RawIx2> for BoundedIx2<N_ROWS, N_COLS> {
+    type Error = crate::errors::VError;
+
+    fn try_from(RawIx2 { row_ix, col_ix }: RawIx2) -> Result<Self, Self::Error> {
+        Self::new(row_ix, col_ix).ok_or(crate::errors::VError::IndexError)
+    }
+}
+
 impl<const N_ROWS: usize, const N_COLS: usize> Ord for BoundedIx2<N_ROWS, N_COLS> {
     fn cmp(&self, other: &Self) -> Ordering {
         match self.row_ix.cmp(&other.row_ix) {
@@ -62,10 +82,53 @@ impl<const N_ROWS: usize, const N_COLS: usize> BoundedIx2<N_ROWS, N_COLS> {
         self.row_ix
     }
 
+    /// whether the index is in the first or last row or column
+    pub const fn is_edge(&self) -> bool {
+        self.row_ix == 0
+            || self.row_ix == N_ROWS - 1
+            || self.col_ix == 0
+            || self.col_ix == N_COLS - 1
+    }
+    /// whether the index is in both the first or last row and the first or last column
+    pub const fn is_corner(&self) -> bool {
+        (self.row_ix == 0 || self.row_ix == N_ROWS - 1)
+            && (self.col_ix == 0 || self.col_ix == N_COLS - 1)
+    }
+
     /// convert to 1d index
     pub fn as_usize(&self) -> usize {
         self.row_ix * N_COLS + self.col_ix
     }
+    /// convert from 1d index, returning `None` if out of bounds
+    pub fn from_usize(ix: usize) -> Option<Self> {
+        ix.checked_div(N_COLS)
+            .and_then(|row_ix| Self::new(row_ix, ix % N_COLS))
+    }
+    /// offset by the given numbers of rows and columns, wrapping around the edges
+    pub fn offset_wrapping(&self, d_row: isize, d_col: isize) -> Self {
+        Self {
+            row_ix: (self.row_ix as isize + d_row.rem_euclid(N_ROWS as isize))
+                .rem_euclid(N_ROWS as isize) as usize,
+            col_ix: (self.col_ix as isize + d_col.rem_euclid(N_COLS as isize))
+                .rem_euclid(N_COLS as isize) as usize,
+        }
+    }
+    /// offset by the given numbers of rows and columns, returning `None` if out of bounds
+    pub fn try_offset(&self, d_row: isize, d_col: isize) -> Option<Self> {
+        let row_ix = self.row_ix.checked_add_signed(d_row)?;
+        let col_ix = self.col_ix.checked_add_signed(d_col)?;
+        Self::new(row_ix, col_ix)
+    }
+    /// move one step in `dir`, returning `None` if out of bounds
+    pub fn step(&self, dir: Direction) -> Option<Self> {
+        let (d_row, d_col) = dir.delta();
+        self.try_offset(d_row, d_col)
+    }
+    /// move one step in `dir`, wrapping around the edges
+    pub fn step_wrapping(&self, dir: Direction) -> Self {
+        let (d_row, d_col) = dir.delta();
+        self.offset_wrapping(d_row, d_col)
+    }
     /// increase row by 1, returning `None` if out of bounds
     pub fn inc_row(&self) -> Option<Self> {
         if self.row_ix == usize::MAX {
@@ -162,6 +225,54 @@ impl<const N_ROWS: usize, const N_COLS: usize> std::fmt::Display for BoundedIx2<
     }
 }
 
+/// a row index, for call sites that shouldn't be able to mix up rows and columns
+///
+/// ```compile_fail
+/// use multid::{Col, Row, V2};
+/// let v: V2<u8, 2, 2> = V2::new(vec![0, 1, 2, 3]).unwrap();
+/// v.get_rc(Col(0), Row(1));
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Row(pub usize);
+
+/// a column index, for call sites that shouldn't be able to mix up rows and columns
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Col(pub usize);
+
+/// area enclosed by a closed polygon with the given vertices, via the shoelace formula
+///
+/// the last vertex is implicitly joined back to the first
+pub fn polygon_area<const N_ROWS: usize, const N_COLS: usize>(
+    vertices: &[BoundedIx2<N_ROWS, N_COLS>],
+) -> f64 {
+    let twice_area: isize = vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(a, b)| a.x() as isize * b.y() as isize - b.x() as isize * a.y() as isize)
+        .sum();
+    twice_area.unsigned_abs() as f64 / 2.0
+}
+
+/// number of grid points strictly inside a closed polygon with the given vertices, via Pick's
+/// theorem
+///
+/// the last vertex is implicitly joined back to the first
+pub fn interior_points<const N_ROWS: usize, const N_COLS: usize>(
+    vertices: &[BoundedIx2<N_ROWS, N_COLS>],
+) -> usize {
+    fn gcd(a: usize, b: usize) -> usize {
+        if b == 0 { a } else { gcd(b, a % b) }
+    }
+    let boundary: usize = vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(a, b)| gcd(a.x().abs_diff(b.x()), a.y().abs_diff(b.y())))
+        .sum();
+    // A = I + B/2 - 1, so 2I = 2A - B + 2
+    let twice_area = (polygon_area(vertices) * 2.0) as usize;
+    (twice_area + 2).saturating_sub(boundary) / 2
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -259,6 +370,18 @@ mod test {
         );
     }
     #[test]
+    fn test_offset_wrapping() {
+        let corner = I::new(0, 0).unwrap();
+        assert_eq!(I::new(2, 2).unwrap(), corner.offset_wrapping(-1, -1));
+        assert_eq!(I::new(1, 1).unwrap(), corner.offset_wrapping(1, 1));
+        assert_eq!(I::new(1, 2).unwrap(), corner.offset_wrapping(7, -4));
+        let ix = I::new(2, 1).unwrap();
+        assert_eq!(
+            I::new(0, 2).unwrap(),
+            ix.offset_wrapping(isize::MAX, isize::MIN)
+        );
+    }
+    #[test]
     fn test_ord() {
         let b1: BoundedIx2<3, 3> = BoundedIx2 {
             row_ix: 1,
@@ -301,6 +424,105 @@ mod test {
         let actual_cmp = b1.cmp(&b2);
         assert_eq!(std::cmp::Ordering::Greater, actual_cmp);
     }
+    #[test]
+    fn test_polygon_area() {
+        type J = BoundedIx2<8, 8>;
+        let square = [(0, 0), (0, 1), (1, 1), (1, 0)].map(|(r, c)| J::new(r, c).unwrap());
+        assert_eq!(1.0, polygon_area(&square));
+        assert_eq!(0, interior_points(&square));
+        let rect = [(1, 1), (1, 5), (4, 5), (4, 1)].map(|(r, c)| J::new(r, c).unwrap());
+        assert_eq!(12.0, polygon_area(&rect));
+        assert_eq!(6, interior_points(&rect));
+    }
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let ix = I::new(1, 2).unwrap();
+        let json = serde_json::to_string(&ix).unwrap();
+        assert_eq!(r#"{"row_ix":1,"col_ix":2}"#, json);
+        assert_eq!(ix, serde_json::from_str::<I>(&json).unwrap());
+        assert!(serde_json::from_str::<I>(r#"{"row_ix":3,"col_ix":0}"#).is_err());
+    }
+    #[test]
+    fn test_hash() {
+        use std::collections::HashSet;
+        type J = BoundedIx2<5, 5>;
+        let mut set = HashSet::new();
+        assert!(set.insert(J::new(0, 0).unwrap()));
+        assert!(set.insert(J::new(4, 2).unwrap()));
+        assert!(set.insert(J::new(2, 4).unwrap()));
+        assert!(!set.insert(J::new(4, 2).unwrap()));
+        assert_eq!(3, set.len());
+        assert!(set.contains(&J::new(2, 4).unwrap()));
+        assert!(!set.contains(&J::new(1, 1).unwrap()));
+    }
+    #[test]
+    fn test_try_offset() {
+        type J = BoundedIx2<5, 5>;
+        let ix = J::new(1, 1).unwrap();
+        assert_eq!(J::new(2, 2), ix.try_offset(1, 1));
+        assert_eq!(None, ix.try_offset(-2, 0));
+        assert_eq!(None, ix.try_offset(0, isize::MIN));
+        assert_eq!(None, ix.try_offset(4, 0));
+        assert_eq!(J::new(4, 3), ix.try_offset(3, 2));
+        assert_eq!(ix.northwest(), ix.try_offset(-1, -1));
+    }
+    #[test]
+    fn test_step() {
+        let center = I::new(1, 1).unwrap();
+        assert_eq!(center.north(), center.step(Direction::North));
+        assert_eq!(center.southwest(), center.step(Direction::SouthWest));
+        let corner = I::new(0, 2).unwrap();
+        assert_eq!(None, corner.step(Direction::North));
+        assert_eq!(None, corner.step(Direction::East));
+        assert_eq!(None, corner.step(Direction::NorthWest));
+        assert_eq!(I::new(1, 2), corner.step(Direction::South));
+    }
+    #[test]
+    fn test_step_wrapping() {
+        type J = BoundedIx2<4, 4>;
+        let top_left = J::new(0, 0).unwrap();
+        let bottom_right = J::new(3, 3).unwrap();
+        assert_eq!(
+            J::new(3, 0).unwrap(),
+            top_left.step_wrapping(Direction::North)
+        );
+        assert_eq!(
+            J::new(0, 3).unwrap(),
+            top_left.step_wrapping(Direction::West)
+        );
+        assert_eq!(
+            J::new(0, 3).unwrap(),
+            bottom_right.step_wrapping(Direction::South)
+        );
+        assert_eq!(
+            J::new(3, 0).unwrap(),
+            bottom_right.step_wrapping(Direction::East)
+        );
+        assert_eq!(bottom_right, top_left.step_wrapping(Direction::NorthWest));
+        assert_eq!(
+            J::new(1, 2).unwrap(),
+            J::new(1, 1).unwrap().step_wrapping(Direction::East)
+        );
+    }
+    #[test]
+    fn test_is_edge_is_corner() {
+        let corners = [(0, 0), (0, 2), (2, 0), (2, 2)];
+        let edges = [(0, 1), (1, 0), (1, 2), (2, 1)];
+        for (r, c) in corners {
+            let ix = I::new(r, c).unwrap();
+            assert!(ix.is_edge());
+            assert!(ix.is_corner());
+        }
+        for (r, c) in edges {
+            let ix = I::new(r, c).unwrap();
+            assert!(ix.is_edge());
+            assert!(!ix.is_corner());
+        }
+        let center = I::new(1, 1).unwrap();
+        assert!(!center.is_edge());
+        assert!(!center.is_corner());
+    }
 }
 
 pub mod iterators {
@@ -331,10 +553,10 @@ pub mod iterators {
         type Item = BoundedIx2<N_ROWS, N_COLS>;
 
         fn next(&mut self) -> Option<Self::Item> {
-            if self.curr_row < N_ROWS {
+            if N_COLS > 0 && self.curr_row < N_ROWS {
                 let col_ix = self.curr_col;
                 let row_ix = self.curr_row;
-                if self.curr_col == N_COLS - 1 {
+                if self.curr_col + 1 == N_COLS {
                     self.curr_col = 0;
                     self.curr_row += 1;
                 } else {
@@ -347,6 +569,37 @@ pub mod iterators {
         }
     }
 
+    /// whether offsetting an index by at most one row and one column stays in bounds
+    fn step_in_bounds<const N_ROWS: usize, const N_COLS: usize>(
+        ix: &BoundedIx2<N_ROWS, N_COLS>,
+        (d_row, d_col): (isize, isize),
+    ) -> bool {
+        let row_ok = match d_row {
+            -1 => ix.row_ix > 0,
+            1 => ix.row_ix + 1 < N_ROWS,
+            _ => true,
+        };
+        let col_ok = match d_col {
+            -1 => ix.col_ix > 0,
+            1 => ix.col_ix + 1 < N_COLS,
+            _ => true,
+        };
+        row_ok && col_ok
+    }
+
+    /// row and column offsets of the eight neighbors of an index, in the order `Ix2Neighbors`
+    /// yields them
+    pub(crate) const NEIGHBOR_OFFSETS: [(isize, isize); 8] = [
+        (-1, -1),
+        (-1, 0),
+        (-1, 1),
+        (0, -1),
+        (0, 1),
+        (1, -1),
+        (1, 0),
+        (1, 1),
+    ];
+
     /// iterator over the (in-bounds) neighbors of an index
     pub struct Ix2Neighbors<const N_ROWS: usize, const N_COLS: usize> {
         start: BoundedIx2<N_ROWS, N_COLS>,
@@ -357,6 +610,14 @@ pub mod iterators {
         pub fn new(start: BoundedIx2<N_ROWS, N_COLS>) -> Self {
             Self { start, curr_ix: 0 }
         }
+
+        /// number of neighbors not yet yielded
+        fn remaining(&self) -> usize {
+            NEIGHBOR_OFFSETS[self.curr_ix as usize..]
+                .iter()
+                .filter(|offset| step_in_bounds(&self.start, **offset))
+                .count()
+        }
     }
 
     impl<const N_ROWS: usize, const N_COLS: usize> Iterator for Ix2Neighbors<N_ROWS, N_COLS> {
@@ -382,6 +643,15 @@ pub mod iterators {
             }
             None
         }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            let remaining = self.remaining();
+            (remaining, Some(remaining))
+        }
+
+        fn count(self) -> usize {
+            self.remaining()
+        }
     }
 
     /// iterator over the (in-bounds) cardinal neighbors (north, east, south, west) of an index
@@ -391,9 +661,20 @@ pub mod iterators {
     }
 
     impl<const N_ROWS: usize, const N_COLS: usize> Ix2CardinalNeighbors<N_ROWS, N_COLS> {
+        /// row and column offsets of each neighbor, in iteration order
+        const OFFSETS: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
         pub fn new(start: BoundedIx2<N_ROWS, N_COLS>) -> Self {
             Self { start, curr_ix: 0 }
         }
+
+        /// number of neighbors not yet yielded
+        fn remaining(&self) -> usize {
+            Self::OFFSETS[self.curr_ix as usize..]
+                .iter()
+                .filter(|offset| step_in_bounds(&self.start, **offset))
+                .count()
+        }
     }
 
     impl<const N_ROWS: usize, const N_COLS: usize> Iterator for Ix2CardinalNeighbors<N_ROWS, N_COLS> {
@@ -415,6 +696,15 @@ pub mod iterators {
             }
             None
         }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            let remaining = self.remaining();
+            (remaining, Some(remaining))
+        }
+
+        fn count(self) -> usize {
+            self.remaining()
+        }
     }
 
     /// iterator over rows of indices, top to bottom
@@ -666,5 +956,34 @@ pub mod iterators {
             let actual: Vec<[BoundedIx2<3, 3>; 3]> = cols.collect();
             assert_eq!(actual, expected)
         }
+        #[test]
+        fn test_v2_indices_empty() {
+            assert_eq!(0, V2Indices::<0, 0>::new().count());
+            assert_eq!(0, V2Indices::<3, 0>::new().count());
+            assert_eq!(0, V2Indices::<0, 3>::new().count());
+        }
+        #[test]
+        fn test_neighbors_count() {
+            let center: BoundedIx2<3, 3> = BoundedIx2::new(1, 1).unwrap();
+            let corner: BoundedIx2<3, 3> = BoundedIx2::new(0, 0).unwrap();
+            let edge: BoundedIx2<3, 3> = BoundedIx2::new(2, 1).unwrap();
+            assert_eq!(8, Ix2Neighbors::new(center).count());
+            assert_eq!(3, Ix2Neighbors::new(corner).count());
+            assert_eq!(5, Ix2Neighbors::new(edge).count());
+            assert_eq!(4, Ix2CardinalNeighbors::new(center).count());
+            assert_eq!(2, Ix2CardinalNeighbors::new(corner).count());
+            assert_eq!(3, Ix2CardinalNeighbors::new(edge).count());
+            for ix in V2Indices::<3, 3>::new() {
+                let mut expected = Ix2Neighbors::new(ix).collect::<Vec<_>>().len();
+                let mut neighbors = Ix2Neighbors::new(ix);
+                assert_eq!((expected, Some(expected)), neighbors.size_hint());
+                while neighbors.next().is_some() {
+                    expected -= 1;
+                    assert_eq!((expected, Some(expected)), neighbors.size_hint());
+                }
+                let cardinal = Ix2CardinalNeighbors::new(ix);
+                assert_eq!(cardinal.size_hint().0, cardinal.collect::<Vec<_>>().len());
+            }
+        }
     }
 }
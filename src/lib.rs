@@ -6,10 +6,12 @@
 //! requires `feature(generic_const_exprs)`
 #![allow(incomplete_features)]
 #![feature(generic_const_exprs)]
+pub mod dir;
 pub mod errors;
 pub mod ix;
 pub mod v;
 
-pub use ix::BoundedIx2;
+pub use dir::Direction;
 pub use ix::iterators;
-pub use v::V2;
+pub use ix::{BoundedIx2, Col, Row, interior_points, polygon_area};
+pub use v::{EdgeMode, V2, edit_distance_table, vstack_all};
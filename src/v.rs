@@ -1,13 +1,36 @@
 //! 2d vector type, parameterized by number of rows and columns
+use crate::dir::Direction;
 use crate::errors::VError;
-use crate::ix::BoundedIx2;
-use std::ops::{Index, IndexMut};
+use crate::ix::iterators::{Ix2CardinalNeighbors, Ix2Neighbors, NEIGHBOR_OFFSETS, V2Indices};
+use crate::ix::{BoundedIx2, Col, Row};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::ops::{Add, Index, IndexMut, Mul};
+use std::sync::Arc;
 
 /// 2d vector type, parameterized by number of rows and columns
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawV2<T>"))]
 pub struct V2<T, const N_ROWS: usize, const N_COLS: usize> {
     data: Vec<T>,
 }
 
+/// unvalidated deserialized vector data
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawV2<T> {
+    data: Vec<T>,
+}
+
+#[cfg(feature = "serde")]
+impl<T, const N_ROWS: usize, const N_COLS: usize> TryFrom<RawV2<T>> for V2<T, N_ROWS, N_COLS> {
+    type Error = VError;
+
+    fn try_from(raw: RawV2<T>) -> Result<Self, Self::Error> {
+        Self::new(raw.data)
+    }
+}
+
 impl<T, const N_ROWS: usize, const N_COLS: usize> V2<T, N_ROWS, N_COLS> {
     /// create a new 2d vector from a preexisting 1d vector
     ///
@@ -22,6 +45,34 @@ impl<T, const N_ROWS: usize, const N_COLS: usize> V2<T, N_ROWS, N_COLS> {
             Ok(Self { data })
         }
     }
+    /// create a new 2d vector from an array of rows
+    pub fn from_rows(rows: [[T; N_COLS]; N_ROWS]) -> Self {
+        Self {
+            data: rows.into_iter().flatten().collect(),
+        }
+    }
+    /// create a new 2d vector by calling `f` with each index, in row-major order
+    pub fn from_fn<F: FnMut(BoundedIx2<N_ROWS, N_COLS>) -> T>(f: F) -> Self {
+        Self {
+            data: V2Indices::<N_ROWS, N_COLS>::new().map(f).collect(),
+        }
+    }
+    /// number of rows
+    pub const fn n_rows(&self) -> usize {
+        N_ROWS
+    }
+    /// number of columns
+    pub const fn n_cols(&self) -> usize {
+        N_COLS
+    }
+    /// total number of cells
+    pub const fn len(&self) -> usize {
+        N_ROWS * N_COLS
+    }
+    /// whether the vector has no cells
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
     /// possibly retrieve a reference to a value given a possible index
     pub fn get(&self, ix: Option<BoundedIx2<N_ROWS, N_COLS>>) -> Option<&T> {
         if let Some(i) = ix {
@@ -30,6 +81,20 @@ impl<T, const N_ROWS: usize, const N_COLS: usize> V2<T, N_ROWS, N_COLS> {
             None
         }
     }
+    /// possibly retrieve a reference to a value given a row and column, returning `None` if out of
+    /// bounds
+    pub fn get_rc(&self, Row(row_ix): Row, Col(col_ix): Col) -> Option<&T> {
+        self.get(BoundedIx2::new(row_ix, col_ix))
+    }
+    /// retrieve a reference to the value at the given signed row and column, clamping each to
+    /// the nearest in-bounds value
+    ///
+    /// panics if the vector is empty
+    pub fn get_clamped(&self, row: isize, col: isize) -> &T {
+        let row_ix = row.clamp(0, N_ROWS as isize - 1) as usize;
+        let col_ix = col.clamp(0, N_COLS as isize - 1) as usize;
+        &self.data[row_ix * N_COLS + col_ix]
+    }
     /// possibly retrieve a mutable reference to a value given a possible index
     pub fn get_mut(&mut self, ix: Option<BoundedIx2<N_ROWS, N_COLS>>) -> Option<&mut T> {
         if let Some(i) = ix {
@@ -38,6 +103,1158 @@ impl<T, const N_ROWS: usize, const N_COLS: usize> V2<T, N_ROWS, N_COLS> {
             None
         }
     }
+    /// write each value to its possible index, but only if every index is in bounds
+    ///
+    /// errors without changing the vector if any index is `None`
+    pub fn set_all<I: IntoIterator<Item = (Option<BoundedIx2<N_ROWS, N_COLS>>, T)>>(
+        &mut self,
+        updates: I,
+    ) -> Result<(), VError> {
+        let updates = updates
+            .into_iter()
+            .map(|(ix, v)| ix.map(|i| (i, v)))
+            .collect::<Option<Vec<(BoundedIx2<N_ROWS, N_COLS>, T)>>>()
+            .ok_or(VError::IndexError)?;
+        for (ix, v) in updates {
+            self[ix] = v;
+        }
+        Ok(())
+    }
+    /// exchange the values at two possible indices
+    ///
+    /// errors without changing the vector if either index is `None`
+    pub fn swap(
+        &mut self,
+        a: Option<BoundedIx2<N_ROWS, N_COLS>>,
+        b: Option<BoundedIx2<N_ROWS, N_COLS>>,
+    ) -> Result<(), VError> {
+        let a = self.flat_index(a).ok_or(VError::IndexError)?;
+        let b = self.flat_index(b).ok_or(VError::IndexError)?;
+        self.data.swap(a, b);
+        Ok(())
+    }
+    /// possibly retrieve the values in a row, returning `None` if out of bounds
+    pub fn get_row(&self, row_ix: usize) -> Option<&[T]> {
+        self.row_slices().nth(row_ix)
+    }
+    /// possibly retrieve references to the values in a column, top to bottom, returning `None`
+    /// if out of bounds
+    pub fn get_col(&self, col_ix: usize) -> Option<Vec<&T>> {
+        (col_ix < N_COLS).then(|| self.row_slices().map(|row| &row[col_ix]).collect())
+    }
+    /// possibly convert a possible index into an offset into the backing data, which is stored
+    /// in row-major order
+    pub fn flat_index(&self, ix: Option<BoundedIx2<N_ROWS, N_COLS>>) -> Option<usize> {
+        ix.map(|i| i.as_usize())
+    }
+    /// possibly convert an offset into the backing data into an index, returning `None` if out
+    /// of bounds
+    pub fn index_from_flat(flat: usize) -> Option<BoundedIx2<N_ROWS, N_COLS>> {
+        BoundedIx2::from_usize(flat)
+    }
+    /// iterate over references to the values, in row-major order
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.data.iter()
+    }
+    /// iterate over mutable references to the values, in row-major order
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.data.iter_mut()
+    }
+    /// consume the vector, returning the underlying data in row-major order
+    ///
+    /// the value at row `r`, column `c` is at offset `r * N_COLS + c`
+    pub fn into_inner(self) -> Vec<T> {
+        self.data
+    }
+    /// the underlying data as a slice, in row-major order
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+    /// the underlying data as a mutable slice, in row-major order
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+    /// overwrite every cell, in row-major order, with the result of calling `f`
+    pub fn fill_with<F: FnMut() -> T>(&mut self, f: F) {
+        self.data.fill_with(f);
+    }
+    /// create a new vector by applying `f` to each cell, in row-major order
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> V2<U, N_ROWS, N_COLS> {
+        V2 {
+            data: self.data.iter().map(f).collect(),
+        }
+    }
+    /// create a new vector by applying `f` to each cell and its index, in row-major order
+    pub fn map_indexed<U, F: FnMut(BoundedIx2<N_ROWS, N_COLS>, &T) -> U>(
+        &self,
+        mut f: F,
+    ) -> V2<U, N_ROWS, N_COLS> {
+        V2 {
+            data: V2Indices::<N_ROWS, N_COLS>::new()
+                .zip(self.data.iter())
+                .map(|(ix, v)| f(ix, v))
+                .collect(),
+        }
+    }
+    /// create a new vector by centering `kernel` on each cell and summing the products of the
+    /// kernel's weights and the values beneath them, with `edge` deciding what's read outside the
+    /// vector
+    ///
+    /// the kernel isn't flipped. panics if either of the kernel's dimensions is even, since it
+    /// then has no center
+    pub fn convolve<const KR: usize, const KC: usize>(
+        &self,
+        kernel: &V2<f64, KR, KC>,
+        edge: EdgeMode,
+    ) -> V2<f64, N_ROWS, N_COLS>
+    where
+        T: Into<f64> + Copy,
+    {
+        assert!(
+            KR % 2 == 1 && KC % 2 == 1,
+            "kernel dimensions must be odd, got {KR}x{KC}"
+        );
+        let (mid_row, mid_col) = ((KR / 2) as isize, (KC / 2) as isize);
+        self.map_indexed(|ix, _| {
+            V2Indices::<KR, KC>::new()
+                .map(|k| {
+                    let d_row = k.y() as isize - mid_row;
+                    let d_col = k.x() as isize - mid_col;
+                    let value = match edge {
+                        EdgeMode::Zero => {
+                            ix.try_offset(d_row, d_col).map_or(0.0, |n| self[n].into())
+                        }
+                        EdgeMode::Clamp => (*self
+                            .get_clamped(ix.y() as isize + d_row, ix.x() as isize + d_col))
+                        .into(),
+                        EdgeMode::Wrap => self[ix.offset_wrapping(d_row, d_col)].into(),
+                    };
+                    kernel[k] * value
+                })
+                .sum()
+        })
+    }
+    /// consume the vector, iterating over its rows (top to bottom) as owned vectors
+    pub fn into_rows(self) -> impl Iterator<Item = Vec<T>> {
+        let mut data = self.data.into_iter();
+        (0..N_ROWS).map(move |_| data.by_ref().take(N_COLS).collect())
+    }
+    /// product of the viewing distances from a given index in each cardinal direction
+    ///
+    /// each viewing distance counts the cells up to and including the first one at least as
+    /// high as the starting cell, stopping at the edge of the vector
+    pub fn scenic_score(&self, ix: BoundedIx2<N_ROWS, N_COLS>) -> usize
+    where
+        T: Ord,
+    {
+        let steps: [fn(&BoundedIx2<N_ROWS, N_COLS>) -> Option<BoundedIx2<N_ROWS, N_COLS>>; 4] = [
+            BoundedIx2::north,
+            BoundedIx2::east,
+            BoundedIx2::south,
+            BoundedIx2::west,
+        ];
+        let height = &self[ix];
+        steps
+            .iter()
+            .map(|step| {
+                let mut distance = 0;
+                let mut curr = step(&ix);
+                while let Some(next) = curr {
+                    distance += 1;
+                    if self[next] >= *height {
+                        break;
+                    }
+                    curr = step(&next);
+                }
+                distance
+            })
+            .product()
+    }
+    /// possibly iterate over the indices of and mutable references to the cells in the
+    /// rectangle bounded (inclusively) by two indices
+    ///
+    /// returns `None` if `top_left` is below or to the right of `bottom_right`
+    pub fn iter_region_mut(
+        &mut self,
+        top_left: BoundedIx2<N_ROWS, N_COLS>,
+        bottom_right: BoundedIx2<N_ROWS, N_COLS>,
+    ) -> Option<impl Iterator<Item = (BoundedIx2<N_ROWS, N_COLS>, &mut T)>> {
+        if top_left.y() > bottom_right.y() || top_left.x() > bottom_right.x() {
+            None
+        } else {
+            let (min_col, max_col) = (top_left.x(), bottom_right.x());
+            Some(
+                self.data
+                    .chunks_mut(N_COLS)
+                    .enumerate()
+                    .skip(top_left.y())
+                    .take(bottom_right.y() - top_left.y() + 1)
+                    .flat_map(move |(row_ix, row)| {
+                        row[min_col..=max_col]
+                            .iter_mut()
+                            .enumerate()
+                            .map(move |(c, v)| (BoundedIx2::new(row_ix, min_col + c).unwrap(), v))
+                    }),
+            )
+        }
+    }
+    /// count the non-wall cells that can't be reached from the edge of the vector without
+    /// crossing a wall (moving only north, east, south, or west)
+    pub fn count_enclosed(&self, is_wall: impl Fn(&T) -> bool) -> usize {
+        let mut outside = vec![false; self.data.len()];
+        let mut stack: Vec<BoundedIx2<N_ROWS, N_COLS>> = V2Indices::<N_ROWS, N_COLS>::new()
+            .filter(|ix| {
+                (ix.y() == 0 || ix.y() == N_ROWS - 1 || ix.x() == 0 || ix.x() == N_COLS - 1)
+                    && !is_wall(&self[*ix])
+            })
+            .collect();
+        for ix in stack.iter() {
+            outside[ix.as_usize()] = true;
+        }
+        while let Some(ix) = stack.pop() {
+            for n in Ix2CardinalNeighbors::new(ix) {
+                if !outside[n.as_usize()] && !is_wall(&self[n]) {
+                    outside[n.as_usize()] = true;
+                    stack.push(n);
+                }
+            }
+        }
+        self.data
+            .iter()
+            .zip(outside)
+            .filter(|(v, o)| !o && !is_wall(v))
+            .count()
+    }
+    /// iterate over the in-bounds hex neighbors of an index, along with their values
+    ///
+    /// the vector is treated as an "odd-r" offset hex layout: each odd row is shifted half a
+    /// cell to the right, so cells in even rows neighbor the northwest/north and
+    /// southwest/south cells, and cells in odd rows neighbor the north/northeast and
+    /// south/southeast cells, in addition to west and east
+    pub fn hex_neighbors(
+        &self,
+        ix: BoundedIx2<N_ROWS, N_COLS>,
+    ) -> impl Iterator<Item = (BoundedIx2<N_ROWS, N_COLS>, &T)> {
+        let neighbors = if ix.y().is_multiple_of(2) {
+            [
+                ix.northwest(),
+                ix.north(),
+                ix.west(),
+                ix.east(),
+                ix.southwest(),
+                ix.south(),
+            ]
+        } else {
+            [
+                ix.north(),
+                ix.northeast(),
+                ix.west(),
+                ix.east(),
+                ix.south(),
+                ix.southeast(),
+            ]
+        };
+        neighbors.into_iter().flatten().map(|n| (n, &self[n]))
+    }
+    /// all indices of the vector, sorted by the values they point to
+    ///
+    /// the sort is stable, so indices of equal values stay in row-major order
+    pub fn argsort(&self) -> Vec<BoundedIx2<N_ROWS, N_COLS>>
+    where
+        T: Ord,
+    {
+        let mut ixs: Vec<BoundedIx2<N_ROWS, N_COLS>> = V2Indices::<N_ROWS, N_COLS>::new().collect();
+        ixs.sort_by(|a, b| self[*a].cmp(&self[*b]));
+        ixs
+    }
+    /// length (in cells) of the longest path moving between cardinal neighbors that is strictly
+    /// increasing in value
+    ///
+    /// cells are visited in descending order (see `argsort`), so every cell's larger neighbors
+    /// have already been scored when it is reached
+    pub fn longest_increasing_path(&self) -> usize
+    where
+        T: Ord,
+    {
+        let mut lengths = vec![1; self.data.len()];
+        for ix in self.argsort().into_iter().rev() {
+            lengths[ix.as_usize()] = Ix2CardinalNeighbors::new(ix)
+                .filter(|n| self[*n] > self[ix])
+                .map(|n| lengths[n.as_usize()] + 1)
+                .max()
+                .unwrap_or(1);
+        }
+        lengths.into_iter().max().unwrap_or(0)
+    }
+    /// indices of the cells strictly lower than all of their in-bounds cardinal neighbors, in
+    /// row-major order
+    pub fn local_minima(&self) -> Vec<BoundedIx2<N_ROWS, N_COLS>>
+    where
+        T: Ord,
+    {
+        V2Indices::<N_ROWS, N_COLS>::new()
+            .filter(|ix| Ix2CardinalNeighbors::new(*ix).all(|n| self[*ix] < self[n]))
+            .collect()
+    }
+    /// indices of the cells strictly higher than all of their in-bounds cardinal neighbors, in
+    /// row-major order
+    pub fn local_maxima(&self) -> Vec<BoundedIx2<N_ROWS, N_COLS>>
+    where
+        T: Ord,
+    {
+        V2Indices::<N_ROWS, N_COLS>::new()
+            .filter(|ix| Ix2CardinalNeighbors::new(*ix).all(|n| self[*ix] > self[n]))
+            .collect()
+    }
+    /// label each cell with the basin it drains into
+    ///
+    /// cells are visited in ascending order (see `argsort`); a cell with no already-labeled
+    /// cardinal neighbors starts a new basin, otherwise it joins the basin of its lowest labeled
+    /// neighbor, with ties going to whichever comes first in north, east, south, west order
+    ///
+    /// basins are numbered from 0 in the order they're found
+    pub fn fill_basins(&self) -> V2<usize, N_ROWS, N_COLS>
+    where
+        T: Ord,
+    {
+        let mut labels: Vec<Option<usize>> = vec![None; self.data.len()];
+        let mut n_basins = 0;
+        for ix in self.argsort() {
+            let lowest = Ix2CardinalNeighbors::new(ix)
+                .filter_map(|n| labels[n.as_usize()].map(|label| (n, label)))
+                .min_by(|(a, _), (b, _)| self[*a].cmp(&self[*b]));
+            labels[ix.as_usize()] = Some(if let Some((_, label)) = lowest {
+                label
+            } else {
+                n_basins += 1;
+                n_basins - 1
+            });
+        }
+        V2 {
+            data: labels.into_iter().map(|l| l.unwrap()).collect(),
+        }
+    }
+    /// possibly trace the outline of the region containing `start` via Moore-neighbor tracing
+    ///
+    /// returns `None` unless `start` is inside the region and on its boundary (i.e. at least one
+    /// of its cardinal neighbors is outside the region or the vector); otherwise returns the
+    /// region's border cells in clockwise order, beginning with `start`
+    pub fn trace_contour(
+        &self,
+        start: BoundedIx2<N_ROWS, N_COLS>,
+        inside: impl Fn(&T) -> bool,
+    ) -> Option<Vec<BoundedIx2<N_ROWS, N_COLS>>> {
+        // clockwise, beginning with north
+        const RING: [(isize, isize); 8] = [
+            (-1, 0),
+            (-1, 1),
+            (0, 1),
+            (1, 1),
+            (1, 0),
+            (1, -1),
+            (0, -1),
+            (-1, -1),
+        ];
+        let to_ix = |(row_ix, col_ix): (isize, isize)| {
+            usize::try_from(row_ix)
+                .ok()
+                .zip(usize::try_from(col_ix).ok())
+                .and_then(|(r, c)| BoundedIx2::<N_ROWS, N_COLS>::new(r, c))
+        };
+        let is_inside = |p: (isize, isize)| to_ix(p).is_some_and(|ix| inside(&self[ix]));
+        if !inside(&self[start]) {
+            return None;
+        }
+        let s = (start.y() as isize, start.x() as isize);
+        let backtrack = [(0, -1), (-1, 0), (0, 1), (1, 0)]
+            .into_iter()
+            .map(|(dr, dc)| (s.0 + dr, s.1 + dc))
+            .find(|p| !is_inside(*p))?;
+        let mut contour = vec![start];
+        let (mut p, mut b) = (s, backtrack);
+        let mut first_step = None;
+        loop {
+            let k = RING
+                .iter()
+                .position(|(dr, dc)| (p.0 + dr, p.1 + dc) == b)
+                .unwrap();
+            let mut prev = b;
+            let mut next = None;
+            for i in 1..=8 {
+                let (dr, dc) = RING[(k + i) % 8];
+                let candidate = (p.0 + dr, p.1 + dc);
+                if is_inside(candidate) {
+                    next = Some(candidate);
+                    break;
+                }
+                prev = candidate;
+            }
+            let Some(c) = next else {
+                // `start` is an isolated cell
+                return Some(contour);
+            };
+            if p == s {
+                match first_step {
+                    None => first_step = Some(c),
+                    Some(f) if f == c => break,
+                    _ => {}
+                }
+            }
+            p = c;
+            b = prev;
+            contour.push(to_ix(p).unwrap());
+        }
+        // the last step returned to `start`
+        contour.pop();
+        Some(contour)
+    }
+    /// count the cells reachable from `start` in at most `steps` moves north, east, south, or
+    /// west through cells satisfying `passable`, including `start` itself
+    pub fn reachable_within(
+        &self,
+        start: BoundedIx2<N_ROWS, N_COLS>,
+        steps: usize,
+        passable: impl Fn(&T) -> bool,
+    ) -> usize {
+        self.bfs_distances(start, passable)
+            .into_iter()
+            .filter(|d| d.is_some_and(|d| d <= steps))
+            .count()
+    }
+    /// count the cells that can be reached from `start` in exactly `steps` moves north, east,
+    /// south, or west through cells satisfying `passable`, allowing moves back and forth
+    ///
+    /// this is every cell whose shortest distance from `start` is at most `steps` and has the
+    /// same parity as `steps`
+    pub fn reachable_exactly(
+        &self,
+        start: BoundedIx2<N_ROWS, N_COLS>,
+        steps: usize,
+        passable: impl Fn(&T) -> bool,
+    ) -> usize {
+        self.bfs_distances(start, passable)
+            .into_iter()
+            .filter(|d| d.is_some_and(|d| d <= steps && d % 2 == steps % 2))
+            .count()
+    }
+    /// create a new vector holding the shortest step counts (moving north, east, south, or west
+    /// through cells satisfying `passable`) from `start` to each cell, or `None` for cells that
+    /// can't be reached
+    ///
+    /// `start` is always at distance 0
+    pub fn bfs_distances<P: Fn(&T) -> bool>(
+        &self,
+        start: BoundedIx2<N_ROWS, N_COLS>,
+        passable: P,
+    ) -> V2<Option<usize>, N_ROWS, N_COLS> {
+        let mut distances: V2<Option<usize>, N_ROWS, N_COLS> = V2::default();
+        distances[start] = Some(0);
+        let mut queue = VecDeque::from([start]);
+        while let Some(ix) = queue.pop_front() {
+            let d = distances[ix].unwrap();
+            for n in Ix2CardinalNeighbors::new(ix) {
+                if distances[n].is_none() && passable(&self[n]) {
+                    distances[n] = Some(d + 1);
+                    queue.push_back(n);
+                }
+            }
+        }
+        distances
+    }
+    /// indices of every cell reachable from `start` through cardinal neighbors satisfying
+    /// `predicate`, in breadth-first order
+    ///
+    /// empty if `start` itself doesn't satisfy `predicate`
+    pub fn region_of<P: Fn(&T) -> bool>(
+        &self,
+        start: BoundedIx2<N_ROWS, N_COLS>,
+        predicate: P,
+    ) -> Vec<BoundedIx2<N_ROWS, N_COLS>> {
+        if !predicate(&self[start]) {
+            return Vec::new();
+        }
+        let mut visited = HashSet::from([start]);
+        let mut region = vec![start];
+        let mut next = 0;
+        while let Some(&ix) = region.get(next) {
+            next += 1;
+            for n in Ix2CardinalNeighbors::new(ix) {
+                if predicate(&self[n]) && visited.insert(n) {
+                    region.push(n);
+                }
+            }
+        }
+        region
+    }
+    /// create a new vector by splitting this one into `R`x`C` blocks and reducing the cells of
+    /// each block (in row-major order) to a single value
+    ///
+    /// panics if `R` and `C` don't evenly divide the number of rows and columns respectively
+    pub fn block_reduce<const R: usize, const C: usize, U, F: Fn(&[&T]) -> U>(
+        &self,
+        reduce: F,
+    ) -> V2<U, { N_ROWS / R }, { N_COLS / C }> {
+        assert!(
+            N_ROWS.is_multiple_of(R) && N_COLS.is_multiple_of(C),
+            "block dimensions must evenly divide vector dimensions"
+        );
+        let mut data = Vec::with_capacity((N_ROWS / R) * (N_COLS / C));
+        let mut block = Vec::with_capacity(R * C);
+        for block_row in 0..N_ROWS / R {
+            for block_col in 0..N_COLS / C {
+                block.clear();
+                for row_ix in block_row * R..(block_row + 1) * R {
+                    let start = row_ix * N_COLS + block_col * C;
+                    block.extend(self.data[start..start + C].iter());
+                }
+                data.push(reduce(&block));
+            }
+        }
+        V2 { data }
+    }
+    /// iterate over every band of `H` consecutive rows, top to bottom, as vectors of references
+    ///
+    /// there are `N_ROWS - H + 1` bands, or none if `H` is greater than the number of rows
+    pub fn row_bands<const H: usize>(&self) -> impl Iterator<Item = V2<&T, H, N_COLS>> {
+        (0..(N_ROWS + 1).saturating_sub(H)).map(move |top| V2 {
+            data: self.data[top * N_COLS..(top + H) * N_COLS].iter().collect(),
+        })
+    }
+    /// iterate over every `WR`x`WC` window of the vector, as vectors of references, in row-major
+    /// order of their top-left corners
+    ///
+    /// there are `(N_ROWS - WR + 1) * (N_COLS - WC + 1)` windows, or none if the window doesn't
+    /// fit
+    pub fn windows<const WR: usize, const WC: usize>(
+        &self,
+    ) -> impl Iterator<Item = V2<&T, WR, WC>> {
+        let n_cols = (N_COLS + 1).saturating_sub(WC);
+        (0..(N_ROWS + 1).saturating_sub(WR))
+            .flat_map(move |top| (0..n_cols).map(move |left| (top, left)))
+            .map(move |(top, left)| V2 {
+                data: self
+                    .row_slices()
+                    .skip(top)
+                    .take(WR)
+                    .flat_map(|row| &row[left..left + WC])
+                    .collect(),
+            })
+    }
+    /// adjacency matrix of the cells, indexed by their offsets into the backing data, where two
+    /// cells are adjacent if they're cardinal neighbors and `connected` holds for their values
+    ///
+    /// the result has `(N_ROWS * N_COLS)^2` cells, so this is only suitable for small vectors
+    pub fn grid_adjacency(
+        &self,
+        connected: impl Fn(&T, &T) -> bool,
+    ) -> V2<bool, { N_ROWS * N_COLS }, { N_ROWS * N_COLS }> {
+        let len = N_ROWS * N_COLS;
+        let mut data = vec![false; len * len];
+        for ix in V2Indices::<N_ROWS, N_COLS>::new() {
+            for n in Ix2CardinalNeighbors::new(ix) {
+                if connected(&self[ix], &self[n]) {
+                    data[ix.as_usize() * len + n.as_usize()] = true;
+                }
+            }
+        }
+        V2 { data }
+    }
+    /// iterate over each cell's index and value along with its eight neighbors, wrapping around
+    /// the edges of the vector
+    ///
+    /// neighbors are in the same order as `Ix2Neighbors` yields them
+    pub fn cells_with_wrapped_neighbors(
+        &self,
+    ) -> impl Iterator<Item = (BoundedIx2<N_ROWS, N_COLS>, &T, [&T; 8])> {
+        V2Indices::<N_ROWS, N_COLS>::new().map(move |ix| {
+            let neighbors =
+                NEIGHBOR_OFFSETS.map(|(d_row, d_col)| &self[ix.offset_wrapping(d_row, d_col)]);
+            (ix, &self[ix], neighbors)
+        })
+    }
+    /// the cell above `ix`, wrapping around the edges of the vector
+    pub fn north_wrapping(&self, ix: BoundedIx2<N_ROWS, N_COLS>) -> &T {
+        &self[ix.offset_wrapping(-1, 0)]
+    }
+    /// the cell below `ix`, wrapping around the edges of the vector
+    pub fn south_wrapping(&self, ix: BoundedIx2<N_ROWS, N_COLS>) -> &T {
+        &self[ix.offset_wrapping(1, 0)]
+    }
+    /// the cell to the right of `ix`, wrapping around the edges of the vector
+    pub fn east_wrapping(&self, ix: BoundedIx2<N_ROWS, N_COLS>) -> &T {
+        &self[ix.offset_wrapping(0, 1)]
+    }
+    /// the cell to the left of `ix`, wrapping around the edges of the vector
+    pub fn west_wrapping(&self, ix: BoundedIx2<N_ROWS, N_COLS>) -> &T {
+        &self[ix.offset_wrapping(0, -1)]
+    }
+    /// the cell above and to the right of `ix`, wrapping around the edges of the vector
+    pub fn northeast_wrapping(&self, ix: BoundedIx2<N_ROWS, N_COLS>) -> &T {
+        &self[ix.offset_wrapping(-1, 1)]
+    }
+    /// the cell above and to the left of `ix`, wrapping around the edges of the vector
+    pub fn northwest_wrapping(&self, ix: BoundedIx2<N_ROWS, N_COLS>) -> &T {
+        &self[ix.offset_wrapping(-1, -1)]
+    }
+    /// the cell below and to the right of `ix`, wrapping around the edges of the vector
+    pub fn southeast_wrapping(&self, ix: BoundedIx2<N_ROWS, N_COLS>) -> &T {
+        &self[ix.offset_wrapping(1, 1)]
+    }
+    /// the cell below and to the left of `ix`, wrapping around the edges of the vector
+    pub fn southwest_wrapping(&self, ix: BoundedIx2<N_ROWS, N_COLS>) -> &T {
+        &self[ix.offset_wrapping(1, -1)]
+    }
+    /// iterate over the eight neighbors of `ix`, wrapping around the edges of the vector
+    ///
+    /// neighbors are in the same order as `Ix2Neighbors` yields them; on small vectors the same
+    /// cell may appear more than once (see `unique_wrapping_neighbors`)
+    pub fn neighbors_wrapping_of(
+        &self,
+        ix: BoundedIx2<N_ROWS, N_COLS>,
+    ) -> impl Iterator<Item = &T> {
+        NEIGHBOR_OFFSETS
+            .into_iter()
+            .map(move |(d_row, d_col)| &self[ix.offset_wrapping(d_row, d_col)])
+    }
+    /// iterate over the distinct indices and values of the eight neighbors of `ix`, wrapping
+    /// around the edges of the vector
+    ///
+    /// on small vectors several offsets can wrap to the same cell (or back to `ix` itself); each
+    /// cell other than `ix` is yielded once, at its first position in `Ix2Neighbors` order
+    pub fn unique_wrapping_neighbors(
+        &self,
+        ix: BoundedIx2<N_ROWS, N_COLS>,
+    ) -> impl Iterator<Item = (BoundedIx2<N_ROWS, N_COLS>, &T)> {
+        let mut seen = vec![ix];
+        NEIGHBOR_OFFSETS
+            .into_iter()
+            .filter_map(move |(d_row, d_col)| {
+                let n = ix.offset_wrapping(d_row, d_col);
+                if seen.contains(&n) {
+                    None
+                } else {
+                    seen.push(n);
+                    Some((n, &self[n]))
+                }
+            })
+    }
+    /// iterate over the values of the in-bounds cells within Chebyshev distance `radius` of `ix`,
+    /// excluding `ix` itself, in row-major order
+    pub fn moore_neighborhood(
+        &self,
+        ix: BoundedIx2<N_ROWS, N_COLS>,
+        radius: usize,
+    ) -> impl Iterator<Item = &T> {
+        let rows = ix.y().saturating_sub(radius)
+            ..ix.y().saturating_add(radius).saturating_add(1).min(N_ROWS);
+        let cols = ix.x().saturating_sub(radius)
+            ..ix.x().saturating_add(radius).saturating_add(1).min(N_COLS);
+        rows.flat_map(move |row_ix| cols.clone().map(move |col_ix| (row_ix, col_ix)))
+            .filter(move |&(row_ix, col_ix)| (row_ix, col_ix) != (ix.y(), ix.x()))
+            .map(|(row_ix, col_ix)| &self.data[row_ix * N_COLS + col_ix])
+    }
+    /// iterate over the values of the in-bounds cells within Manhattan distance `radius` of `ix`,
+    /// excluding `ix` itself, in row-major order
+    pub fn von_neumann_neighborhood(
+        &self,
+        ix: BoundedIx2<N_ROWS, N_COLS>,
+        radius: usize,
+    ) -> impl Iterator<Item = &T> {
+        let rows = ix.y().saturating_sub(radius)
+            ..ix.y().saturating_add(radius).saturating_add(1).min(N_ROWS);
+        rows.flat_map(move |row_ix| {
+            let reach = radius - row_ix.abs_diff(ix.y());
+            let cols = ix.x().saturating_sub(reach)
+                ..ix.x().saturating_add(reach).saturating_add(1).min(N_COLS);
+            cols.map(move |col_ix| (row_ix, col_ix))
+        })
+        .filter(move |&(row_ix, col_ix)| (row_ix, col_ix) != (ix.y(), ix.x()))
+        .map(|(row_ix, col_ix)| &self.data[row_ix * N_COLS + col_ix])
+    }
+    /// apply `f` to the index and value of each in-bounds neighbor of `ix`, in the same order as
+    /// `Ix2Neighbors` yields them, returning the first `Some` result
+    pub fn find_neighbor<B, F: Fn(BoundedIx2<N_ROWS, N_COLS>, &T) -> Option<B>>(
+        &self,
+        ix: BoundedIx2<N_ROWS, N_COLS>,
+        f: F,
+    ) -> Option<B> {
+        Ix2Neighbors::new(ix).find_map(|n| f(n, &self[n]))
+    }
+    /// create a new vector by applying `f` to each cell and its in-bounds neighbors
+    ///
+    /// neighbors are passed in the same order as `Ix2Neighbors` yields them
+    pub fn map_with_neighbors<U, F: Fn(&T, &[&T]) -> U>(&self, f: F) -> V2<U, N_ROWS, N_COLS> {
+        self.map_indexed(|ix, v| {
+            let neighbors: Vec<&T> = Ix2Neighbors::new(ix).map(|n| &self[n]).collect();
+            f(v, &neighbors)
+        })
+    }
+    /// iterate over the indices and values of the in-bounds cells at the given `(row, col)`
+    /// offsets from `ix`, in the order the offsets are listed
+    pub fn custom_neighbors<'a>(
+        &'a self,
+        ix: BoundedIx2<N_ROWS, N_COLS>,
+        deltas: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (BoundedIx2<N_ROWS, N_COLS>, &'a T)> {
+        deltas
+            .iter()
+            .filter_map(move |&(d_row, d_col)| ix.try_offset(d_row, d_col))
+            .map(|n| (n, &self[n]))
+    }
+    /// create a new vector holding the number of in-bounds neighbors of each cell
+    pub fn degree_grid(&self) -> V2<u8, N_ROWS, N_COLS> {
+        V2 {
+            data: V2Indices::<N_ROWS, N_COLS>::new()
+                .map(|ix| Ix2Neighbors::new(ix).count() as u8)
+                .collect(),
+        }
+    }
+    /// multiply the vector, as an `N_ROWS`x`N_COLS` matrix, by a column vector
+    pub fn mul_vec(&self, v: &[T; N_COLS]) -> [T; N_ROWS]
+    where
+        T: Mul<Output = T> + Add<Output = T> + Default + Copy,
+    {
+        std::array::from_fn(|row_ix| {
+            self.data[row_ix * N_COLS..(row_ix + 1) * N_COLS]
+                .iter()
+                .zip(v.iter())
+                .fold(T::default(), |acc, (a, b)| acc + *a * *b)
+        })
+    }
+    /// possibly retrieve references to the values at each possible index along a path, in
+    /// order, returning `None` if any index is `None`
+    pub fn values_along(&self, path: &[Option<BoundedIx2<N_ROWS, N_COLS>>]) -> Option<Vec<&T>> {
+        path.iter().map(|ix| self.get(*ix)).collect()
+    }
+    /// possibly find the largest possible smallest value crossed by any path from `start` to
+    /// `goal` moving north, east, south, or west, including both endpoints
+    pub fn widest_path(
+        &self,
+        start: BoundedIx2<N_ROWS, N_COLS>,
+        goal: BoundedIx2<N_ROWS, N_COLS>,
+    ) -> Option<T>
+    where
+        T: Ord + Copy,
+    {
+        let mut best: Vec<Option<T>> = vec![None; self.data.len()];
+        best[start.as_usize()] = Some(self[start]);
+        let mut heap = BinaryHeap::from([(self[start], start)]);
+        while let Some((width, ix)) = heap.pop() {
+            if ix == goal {
+                return Some(width);
+            }
+            if best[ix.as_usize()].is_some_and(|b| b > width) {
+                continue;
+            }
+            for n in Ix2CardinalNeighbors::new(ix) {
+                let candidate = width.min(self[n]);
+                if best[n.as_usize()].is_none_or(|b| candidate > b) {
+                    best[n.as_usize()] = Some(candidate);
+                    heap.push((candidate, n));
+                }
+            }
+        }
+        None
+    }
+    /// count the groups of cells satisfying `is_region` that are connected through any of their
+    /// eight neighbors, so cells touching only diagonally are in the same region
+    pub fn region_count_8<F: Fn(&T) -> bool>(&self, is_region: F) -> usize {
+        self.label_components(is_region, true).1
+    }
+    /// label each maximal group of connected cells satisfying `predicate` with a distinct
+    /// number, counting up from 0 in row-major order of each group's first cell
+    ///
+    /// cells are connected through their cardinal neighbors, or all eight neighbors if
+    /// `diagonal` is set. cells not satisfying `predicate` are labeled `None`. returns the labels
+    /// and the number of groups
+    pub fn label_components<P: Fn(&T) -> bool>(
+        &self,
+        predicate: P,
+        diagonal: bool,
+    ) -> (V2<Option<usize>, N_ROWS, N_COLS>, usize) {
+        let mut labels: V2<Option<usize>, N_ROWS, N_COLS> = V2::default();
+        let mut count = 0;
+        for start in V2Indices::<N_ROWS, N_COLS>::new() {
+            if labels[start].is_some() || !predicate(&self[start]) {
+                continue;
+            }
+            labels[start] = Some(count);
+            let mut stack = vec![start];
+            while let Some(ix) = stack.pop() {
+                let neighbors = Ix2Neighbors::new(ix)
+                    .filter(|n| diagonal || n.x() == ix.x() || n.y() == ix.y());
+                for n in neighbors {
+                    if labels[n].is_none() && predicate(&self[n]) {
+                        labels[n] = Some(count);
+                        stack.push(n);
+                    }
+                }
+            }
+            count += 1;
+        }
+        (labels, count)
+    }
+    /// find the cheapest 8-connected path from `start` to `goal` through cells satisfying
+    /// `passable`, where cardinal moves cost 1 and diagonal moves cost √2
+    ///
+    /// diagonal moves may not cut a corner where both orthogonal cells are impassable
+    ///
+    /// returns the total cost and the path, including `start` and `goal`, or `None` if `goal` is
+    /// unreachable
+    pub fn astar_diagonal(
+        &self,
+        start: BoundedIx2<N_ROWS, N_COLS>,
+        goal: BoundedIx2<N_ROWS, N_COLS>,
+        passable: impl Fn(&T) -> bool,
+    ) -> Option<(f64, Vec<BoundedIx2<N_ROWS, N_COLS>>)> {
+        let octile = |ix: BoundedIx2<N_ROWS, N_COLS>| {
+            let dx = ix.x().abs_diff(goal.x()) as f64;
+            let dy = ix.y().abs_diff(goal.y()) as f64;
+            dx.max(dy) + (std::f64::consts::SQRT_2 - 1.0) * dx.min(dy)
+        };
+        let mut costs: Vec<Option<f64>> = vec![None; self.data.len()];
+        let mut came_from: Vec<Option<BoundedIx2<N_ROWS, N_COLS>>> = vec![None; self.data.len()];
+        costs[start.as_usize()] = Some(0.0);
+        let mut heap = BinaryHeap::from([(Reverse(Cost(octile(start))), start)]);
+        while let Some((Reverse(Cost(estimate)), ix)) = heap.pop() {
+            let cost = costs[ix.as_usize()].unwrap();
+            if ix == goal {
+                let mut path = vec![goal];
+                while let Some(prev) = came_from[path[path.len() - 1].as_usize()] {
+                    path.push(prev);
+                }
+                path.reverse();
+                return Some((cost, path));
+            }
+            if estimate > cost + octile(ix) {
+                continue;
+            }
+            for n in Ix2Neighbors::new(ix) {
+                if !passable(&self[n]) {
+                    continue;
+                }
+                let step = if n.x() != ix.x() && n.y() != ix.y() {
+                    let corners = [
+                        BoundedIx2::new(ix.y(), n.x()),
+                        BoundedIx2::new(n.y(), ix.x()),
+                    ];
+                    if !corners.into_iter().flatten().any(|c| passable(&self[c])) {
+                        continue;
+                    }
+                    std::f64::consts::SQRT_2
+                } else {
+                    1.0
+                };
+                let candidate = cost + step;
+                if costs[n.as_usize()].is_none_or(|c| candidate < c) {
+                    costs[n.as_usize()] = Some(candidate);
+                    came_from[n.as_usize()] = Some(ix);
+                    heap.push((Reverse(Cost(candidate + octile(n))), n));
+                }
+            }
+        }
+        None
+    }
+    /// find the cheapest path moving north, east, south, or west from `start` to `goal`, where
+    /// `cost` gives the cost of entering a cell (or `None` if it can't be entered) and
+    /// `heuristic` estimates the remaining cost from a cell to `goal`
+    ///
+    /// `heuristic` must never overestimate for the path to be the cheapest. a path whose total
+    /// cost would overflow a `u32` is treated as impassable, and estimates saturate at
+    /// `u32::MAX`. returns the path, including `start` and `goal`, or `None` if `goal` is
+    /// unreachable
+    pub fn astar<C, H>(
+        &self,
+        start: BoundedIx2<N_ROWS, N_COLS>,
+        goal: BoundedIx2<N_ROWS, N_COLS>,
+        cost: C,
+        heuristic: H,
+    ) -> Option<Vec<BoundedIx2<N_ROWS, N_COLS>>>
+    where
+        C: Fn(&T) -> Option<u32>,
+        H: Fn(BoundedIx2<N_ROWS, N_COLS>) -> u32,
+    {
+        let mut costs: Vec<Option<u32>> = vec![None; self.data.len()];
+        let mut came_from: Vec<Option<BoundedIx2<N_ROWS, N_COLS>>> = vec![None; self.data.len()];
+        costs[start.as_usize()] = Some(0);
+        let mut heap = BinaryHeap::from([Reverse((heuristic(start), start))]);
+        while let Some(Reverse((estimate, ix))) = heap.pop() {
+            let so_far = costs[ix.as_usize()].unwrap();
+            if ix == goal {
+                let mut path = vec![goal];
+                while let Some(prev) = came_from[path[path.len() - 1].as_usize()] {
+                    path.push(prev);
+                }
+                path.reverse();
+                return Some(path);
+            }
+            if estimate > so_far.saturating_add(heuristic(ix)) {
+                continue;
+            }
+            for n in Ix2CardinalNeighbors::new(ix) {
+                let Some(candidate) = cost(&self[n]).and_then(|step| so_far.checked_add(step))
+                else {
+                    continue;
+                };
+                if costs[n.as_usize()].is_none_or(|c| candidate < c) {
+                    costs[n.as_usize()] = Some(candidate);
+                    came_from[n.as_usize()] = Some(ix);
+                    heap.push(Reverse((candidate.saturating_add(heuristic(n)), n)));
+                }
+            }
+        }
+        None
+    }
+    /// count the corners of each region, keyed by the label `label` gives its cells
+    ///
+    /// a region's number of corners is also its number of straight sides. cells sharing a label
+    /// are counted together whether or not they are connected
+    pub fn region_corners<F: Fn(&T) -> usize>(&self, label: F) -> HashMap<usize, usize> {
+        let labels = self.map(label);
+        let same = |ix: BoundedIx2<N_ROWS, N_COLS>, other: Option<BoundedIx2<N_ROWS, N_COLS>>| {
+            other.is_some_and(|o| labels[o] == labels[ix])
+        };
+        let mut corners = HashMap::new();
+        for ix in V2Indices::<N_ROWS, N_COLS>::new() {
+            let quadrants = [
+                (ix.north(), ix.east(), ix.northeast()),
+                (ix.south(), ix.east(), ix.southeast()),
+                (ix.south(), ix.west(), ix.southwest()),
+                (ix.north(), ix.west(), ix.northwest()),
+            ];
+            let count = quadrants
+                .into_iter()
+                .filter(|(a, b, diagonal)| {
+                    let (a, b) = (same(ix, *a), same(ix, *b));
+                    (!a && !b) || (a && b && !same(ix, *diagonal))
+                })
+                .count();
+            *corners.entry(labels[ix]).or_insert(0) += count;
+        }
+        corners
+    }
+    /// iterate over the values on the main diagonal (where the row and column are equal), from
+    /// the top-left, stopping at the shorter dimension
+    pub fn diagonal(&self) -> impl Iterator<Item = &T> {
+        (0..N_ROWS.min(N_COLS)).map(|i| &self.data[i * N_COLS + i])
+    }
+    /// sum of the values on the main diagonal (see `diagonal`)
+    pub fn trace(&self) -> T
+    where
+        T: Add<Output = T> + Default + Copy,
+    {
+        self.diagonal().fold(T::default(), |acc, v| acc + *v)
+    }
+    /// iterate over all indices grouped by anti-diagonal (`row + col`), from the top-left corner
+    /// to the bottom-right, and by increasing row within each anti-diagonal
+    pub fn antidiagonal_order(
+        &self,
+    ) -> impl Iterator<Item = BoundedIx2<N_ROWS, N_COLS>> + use<T, N_ROWS, N_COLS> {
+        self.antidiagonal_sums().flat_map(Self::antidiagonal)
+    }
+    /// iterate over the anti-diagonals (`row + col`) of the vector, from the top-left corner to
+    /// the bottom-right, as the indices and values of their cells in increasing row order
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = Vec<(BoundedIx2<N_ROWS, N_COLS>, &T)>> {
+        self.antidiagonal_sums()
+            .map(|sum| Self::antidiagonal(sum).map(|ix| (ix, &self[ix])).collect())
+    }
+    /// encode the vector as comma-separated values, one line per row
+    pub fn to_csv(&self) -> String
+    where
+        T: std::fmt::Display,
+    {
+        let mut csv = String::new();
+        for row in self.row_slices() {
+            let cells: Vec<String> = row.iter().map(|v| v.to_string()).collect();
+            csv.push_str(&cells.join(","));
+            csv.push('\n');
+        }
+        csv
+    }
+    /// parse comma-separated values, one line per row, into a new vector
+    ///
+    /// blank lines at the end of the input are ignored. errors if there's the wrong number of rows
+    /// or columns or any cell can't be parsed
+    pub fn from_csv(s: &str) -> Result<Self, VError>
+    where
+        T: std::str::FromStr,
+    {
+        let rows = s
+            .trim_end_matches(['\r', '\n'])
+            .lines()
+            .map(|line| {
+                line.split(',')
+                    .map(|cell| {
+                        cell.trim()
+                            .parse()
+                            .map_err(|_| VError::ParseError(format!("invalid CSV cell {:?}", cell)))
+                    })
+                    .collect::<Result<Vec<T>, VError>>()
+            })
+            .collect::<Result<Vec<Vec<T>>, VError>>()?;
+        Self::try_from(rows)
+    }
+    /// length of the longest run of consecutive cells equal to `value` along `dir`
+    pub fn max_run(&self, value: &T, dir: Direction) -> usize
+    where
+        T: PartialEq,
+    {
+        V2Indices::<N_ROWS, N_COLS>::new()
+            .filter(|ix| {
+                self[*ix] == *value && ix.step(dir.opposite()).is_none_or(|p| self[p] != *value)
+            })
+            .map(|start| {
+                std::iter::successors(Some(start), |ix| ix.step(dir))
+                    .take_while(|ix| self[*ix] == *value)
+                    .count()
+            })
+            .max()
+            .unwrap_or(0)
+    }
+    /// whether there's a horizontal, vertical, or diagonal run of at least `length` cells equal
+    /// to `value` (see `max_run`)
+    pub fn has_line_of(&self, value: &T, length: usize) -> bool
+    where
+        T: PartialEq,
+    {
+        [
+            Direction::East,
+            Direction::South,
+            Direction::SouthEast,
+            Direction::NorthEast,
+        ]
+        .into_iter()
+        .any(|dir| self.max_run(value, dir) >= length)
+    }
+    /// iterate over the values in a clockwise spiral from the top-left corner inward
+    pub fn spiral(&self) -> impl Iterator<Item = &T> {
+        (0..N_ROWS.min(N_COLS).div_ceil(2))
+            .flat_map(Self::ring_indices)
+            .map(|ix| &self[ix])
+    }
+    /// iterate over the indices and values of the cells on the outer edge of the vector,
+    /// clockwise from the top-left corner
+    pub fn border(&self) -> impl Iterator<Item = (BoundedIx2<N_ROWS, N_COLS>, &T)> {
+        Self::ring_indices(0).into_iter().map(|ix| (ix, &self[ix]))
+    }
+    /// iterate over the indices and values of the cells not on the outer edge of the vector, in
+    /// row-major order
+    pub fn interior(&self) -> impl Iterator<Item = (BoundedIx2<N_ROWS, N_COLS>, &T)> {
+        V2Indices::<N_ROWS, N_COLS>::new()
+            .filter(|ix| !ix.is_edge())
+            .map(|ix| (ix, &self[ix]))
+    }
+    /// reinterpret the vector's row-major data as `R` rows of `C` columns, without copying
+    ///
+    /// only compiles if `R * C` equals the number of cells in the vector
+    ///
+    /// ```compile_fail,E0080
+    /// use multid::V2;
+    /// let v: V2<u8, 2, 6> = V2::new((0..12).collect()).unwrap();
+    /// let _: V2<u8, 5, 2> = v.reshape();
+    /// ```
+    pub fn reshape<const R: usize, const C: usize>(self) -> V2<T, R, C>
+    where
+        [(); R * C - N_ROWS * N_COLS]:,
+        [(); N_ROWS * N_COLS - R * C]:,
+    {
+        V2 { data: self.data }
+    }
+    /// create a new vector without the row at `row_ix`
+    ///
+    /// errors if `row_ix` is out of bounds
+    pub fn remove_row(self, row_ix: usize) -> Result<V2<T, { N_ROWS - 1 }, N_COLS>, VError> {
+        if row_ix >= N_ROWS {
+            return Err(VError::IndexError);
+        }
+        let mut data = self.data;
+        data.drain(row_ix * N_COLS..(row_ix + 1) * N_COLS);
+        Ok(V2 { data })
+    }
+    /// create a new vector without the column at `col_ix`
+    ///
+    /// errors if `col_ix` is out of bounds
+    pub fn remove_col(self, col_ix: usize) -> Result<V2<T, N_ROWS, { N_COLS - 1 }>, VError> {
+        if col_ix >= N_COLS {
+            return Err(VError::IndexError);
+        }
+        let data = self
+            .data
+            .into_iter()
+            .enumerate()
+            .filter(|(i, _)| i % N_COLS != col_ix)
+            .map(|(_, v)| v)
+            .collect();
+        Ok(V2 { data })
+    }
+    /// mirror the vector in place, reversing the order of the columns
+    pub fn flip_horizontal(&mut self) {
+        for row_ix in 0..N_ROWS {
+            self.data[row_ix * N_COLS..(row_ix + 1) * N_COLS].reverse();
+        }
+    }
+    /// mirror the vector in place, reversing the order of the rows
+    pub fn flip_vertical(&mut self) {
+        for row_ix in 0..N_ROWS / 2 {
+            let other_ix = N_ROWS - 1 - row_ix;
+            let (top, bottom) = self.data.split_at_mut(other_ix * N_COLS);
+            top[row_ix * N_COLS..(row_ix + 1) * N_COLS].swap_with_slice(&mut bottom[..N_COLS]);
+        }
+    }
+    /// check that every cell satisfies `pred`
+    ///
+    /// errors with the indices of the offending cells, in row-major order
+    pub fn validate<F: Fn(&T) -> bool>(
+        &self,
+        pred: F,
+    ) -> Result<(), Vec<BoundedIx2<N_ROWS, N_COLS>>> {
+        let invalid: Vec<BoundedIx2<N_ROWS, N_COLS>> = V2Indices::<N_ROWS, N_COLS>::new()
+            .zip(self.data.iter())
+            .filter(|(_, v)| !pred(v))
+            .map(|(ix, _)| ix)
+            .collect();
+        if invalid.is_empty() {
+            Ok(())
+        } else {
+            Err(invalid)
+        }
+    }
+    /// indices of the `ring`th concentric border of the vector (the outer border is ring 0),
+    /// clockwise from its top-left corner
+    fn ring_indices(ring: usize) -> Vec<BoundedIx2<N_ROWS, N_COLS>> {
+        if 2 * ring >= N_ROWS.min(N_COLS) {
+            return Vec::new();
+        }
+        let (top, left) = (ring, ring);
+        let (bottom, right) = (N_ROWS - 1 - ring, N_COLS - 1 - ring);
+        let ix = |row_ix, col_ix| BoundedIx2::new(row_ix, col_ix).unwrap();
+        let mut ixs: Vec<BoundedIx2<N_ROWS, N_COLS>> = (left..=right).map(|c| ix(top, c)).collect();
+        ixs.extend((top + 1..=bottom).map(|r| ix(r, right)));
+        if bottom > top {
+            ixs.extend((left..right).rev().map(|c| ix(bottom, c)));
+        }
+        if right > left {
+            ixs.extend((top + 1..bottom).rev().map(|r| ix(r, left)));
+        }
+        ixs
+    }
+    /// the possible values of `row + col`
+    fn antidiagonal_sums(&self) -> std::ops::Range<usize> {
+        if self.is_empty() {
+            0..0
+        } else {
+            0..N_ROWS + N_COLS - 1
+        }
+    }
+    /// indices of the cells where `row + col == sum`, in increasing row order
+    fn antidiagonal(sum: usize) -> impl Iterator<Item = BoundedIx2<N_ROWS, N_COLS>> {
+        (sum.saturating_sub(N_COLS - 1)..=sum.min(N_ROWS - 1))
+            .filter_map(move |row_ix| BoundedIx2::new(row_ix, sum - row_ix))
+    }
+    /// the rows of the vector as slices, top to bottom
+    fn row_slices(&self) -> impl Iterator<Item = &[T]> {
+        (0..N_ROWS).map(|row_ix| &self.data[row_ix * N_COLS..(row_ix + 1) * N_COLS])
+    }
 }
 
 impl<T, const N_ROWS: usize, const N_COLS: usize> PartialEq for V2<T, N_ROWS, N_COLS>
@@ -49,6 +1266,19 @@ where
     }
 }
 
+impl<T, const N_ROWS: usize, const N_COLS: usize> Eq for V2<T, N_ROWS, N_COLS> where T: Eq {}
+
+impl<T, const N_ROWS: usize, const N_COLS: usize> std::hash::Hash for V2<T, N_ROWS, N_COLS>
+where
+    T: std::hash::Hash,
+{
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        N_ROWS.hash(state);
+        N_COLS.hash(state);
+        self.data.hash(state);
+    }
+}
+
 impl<T, const N_ROWS: usize, const N_COLS: usize> Index<BoundedIx2<N_ROWS, N_COLS>>
     for V2<T, N_ROWS, N_COLS>
 {
@@ -67,10 +1297,119 @@ impl<T, const N_ROWS: usize, const N_COLS: usize> IndexMut<BoundedIx2<N_ROWS, N_
     }
 }
 
+impl<T, const N_ROWS: usize, const N_COLS: usize> IntoIterator for V2<T, N_ROWS, N_COLS> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a, T, const N_ROWS: usize, const N_COLS: usize> IntoIterator for &'a V2<T, N_ROWS, N_COLS> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+impl<T, const N_ROWS: usize, const N_COLS: usize> TryFrom<Vec<Vec<T>>> for V2<T, N_ROWS, N_COLS> {
+    type Error = VError;
+
+    /// create a new 2d vector from a vector of rows
+    ///
+    /// errors if there's the wrong number of rows or any row is the wrong length
+    fn try_from(rows: Vec<Vec<T>>) -> Result<Self, Self::Error> {
+        if rows.len() != N_ROWS {
+            return Err(VError::SizingError {
+                expected: N_ROWS,
+                actual: rows.len(),
+            });
+        }
+        if let Some(row) = rows.iter().find(|row| row.len() != N_COLS) {
+            return Err(VError::SizingError {
+                expected: N_COLS,
+                actual: row.len(),
+            });
+        }
+        Ok(Self {
+            data: rows.into_iter().flatten().collect(),
+        })
+    }
+}
+
+/// stack `N` vectors of the same shape on top of each other, in order
+pub fn vstack_all<T, const N: usize, const R: usize, const C: usize>(
+    parts: [V2<T, R, C>; N],
+) -> V2<T, { R * N }, C> {
+    V2 {
+        data: parts.into_iter().flat_map(|part| part.data).collect(),
+    }
+}
+
+/// the full Levenshtein dynamic-programming table for turning `a` into `b`, as `a.len() + 1`
+/// rows of `b.len() + 1` columns
+///
+/// the cell at row `i`, column `j` holds the edit distance between the first `i` characters of
+/// `a` and the first `j` characters of `b`, so the last cell of the last row holds the distance
+/// between the whole sequences. the lengths of `a` and `b` are only known at runtime, so the
+/// table is nested vectors rather than a `V2`
+pub fn edit_distance_table(a: &[char], b: &[char]) -> Vec<Vec<u32>> {
+    let mut table: Vec<Vec<u32>> = Vec::with_capacity(a.len() + 1);
+    table.push((0..=b.len() as u32).collect());
+    for (i, ca) in a.iter().enumerate() {
+        let prev = &table[i];
+        let mut row = Vec::with_capacity(b.len() + 1);
+        row.push(i as u32 + 1);
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = prev[j] + u32::from(ca != cb);
+            row.push(substitution.min(prev[j + 1] + 1).min(row[j] + 1));
+        }
+        table.push(row);
+    }
+    table
+}
+
 impl<T, const N_ROWS: usize, const N_COLS: usize> V2<T, N_ROWS, N_COLS>
 where
     T: Clone,
 {
+    /// create a new 2d vector by cloning rows out of a larger buffer, where consecutive rows
+    /// begin `stride` elements apart
+    ///
+    /// errors if `stride` is less than the number of columns or the buffer is too short
+    pub fn from_strided(src: &[T], stride: usize) -> Result<Self, VError> {
+        if stride < N_COLS {
+            return Err(VError::SizingError {
+                expected: N_COLS,
+                actual: stride,
+            });
+        }
+        // a length that overflows a usize is reported as usize::MAX
+        let needed = if N_ROWS == 0 {
+            0
+        } else {
+            (N_ROWS - 1)
+                .checked_mul(stride)
+                .and_then(|n| n.checked_add(N_COLS))
+                .unwrap_or(usize::MAX)
+        };
+        if src.len() < needed {
+            Err(VError::SizingError {
+                expected: needed,
+                actual: src.len(),
+            })
+        } else {
+            let mut data = Vec::with_capacity(N_ROWS * N_COLS);
+            for row_ix in 0..N_ROWS {
+                let start = row_ix * stride;
+                data.extend_from_slice(&src[start..start + N_COLS]);
+            }
+            Ok(Self { data })
+        }
+    }
     /// create a clone of this vector with an additional column
     ///
     /// errors if the length of the new column doesn't match the number of rows in the vector
@@ -103,20 +1442,495 @@ where
             Ok(V2 { data: new_data })
         }
     }
-}
-
-impl<T, const N_ROWS: usize, const N_COLS: usize> std::fmt::Debug for V2<T, N_ROWS, N_COLS>
-where
-    T: std::fmt::Debug,
-{
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
-        write!(f, "V2<{}, {}> {{ data: {:?} }}", N_ROWS, N_COLS, self.data)
+    /// create a new vector with the columns of `other` to the right of these
+    pub fn hstack<const OTHER_COLS: usize>(
+        self,
+        other: V2<T, N_ROWS, OTHER_COLS>,
+    ) -> V2<T, N_ROWS, { N_COLS + OTHER_COLS }> {
+        let mut data = Vec::with_capacity(N_ROWS * (N_COLS + OTHER_COLS));
+        for (left, right) in self.row_slices().zip(other.row_slices()) {
+            data.extend(left.iter().cloned());
+            data.extend(right.iter().cloned());
+        }
+        V2 { data }
     }
-}
-
-impl<T, const N_ROWS: usize, const N_COLS: usize> Clone for V2<T, N_ROWS, N_COLS>
-where
-    T: Clone,
+    /// create a new vector with the rows of `other` below these
+    pub fn vstack<const OTHER_ROWS: usize>(
+        self,
+        other: V2<T, OTHER_ROWS, N_COLS>,
+    ) -> V2<T, { N_ROWS + OTHER_ROWS }, N_COLS> {
+        let mut data = self.data;
+        data.extend(other.data);
+        V2 { data }
+    }
+    /// create a new vector where, in each column, every non-empty cell has fallen to the bottom
+    /// (keeping the order of the non-empty cells) and the cells above are filled with `empty`
+    pub fn settle_down(&self, is_empty: impl Fn(&T) -> bool, empty: T) -> V2<T, N_ROWS, N_COLS> {
+        let mut data = vec![empty; self.data.len()];
+        for col_ix in 0..N_COLS {
+            let mut dest_row = N_ROWS;
+            for row_ix in (0..N_ROWS).rev() {
+                let v = &self.data[row_ix * N_COLS + col_ix];
+                if !is_empty(v) {
+                    dest_row -= 1;
+                    data[dest_row * N_COLS + col_ix] = v.clone();
+                }
+            }
+        }
+        V2 { data }
+    }
+    /// create a new vector by repeating each cell into an `R`x`C` block
+    pub fn upscale<const R: usize, const C: usize>(&self) -> V2<T, { N_ROWS * R }, { N_COLS * C }> {
+        let mut data = Vec::with_capacity(N_ROWS * R * N_COLS * C);
+        for row_ix in 0..N_ROWS {
+            let row = &self.data[row_ix * N_COLS..(row_ix + 1) * N_COLS];
+            for _ in 0..R {
+                for v in row.iter() {
+                    data.extend(std::iter::repeat_n(v, C).cloned());
+                }
+            }
+        }
+        V2 { data }
+    }
+    /// create a clone of this vector surrounded by `TOP` rows above, `BOTTOM` rows below, `LEFT`
+    /// columns to the left, and `RIGHT` columns to the right, all holding `fill`
+    pub fn pad_sides<
+        const TOP: usize,
+        const BOTTOM: usize,
+        const LEFT: usize,
+        const RIGHT: usize,
+    >(
+        &self,
+        fill: T,
+    ) -> V2<T, { N_ROWS + TOP + BOTTOM }, { N_COLS + LEFT + RIGHT }> {
+        let width = N_COLS + LEFT + RIGHT;
+        let mut data = Vec::with_capacity((N_ROWS + TOP + BOTTOM) * width);
+        data.extend(std::iter::repeat_n(&fill, TOP * width).cloned());
+        for row in self.row_slices() {
+            data.extend(std::iter::repeat_n(&fill, LEFT).cloned());
+            data.extend(row.iter().cloned());
+            data.extend(std::iter::repeat_n(&fill, RIGHT).cloned());
+        }
+        data.extend(std::iter::repeat_n(&fill, BOTTOM * width).cloned());
+        V2 { data }
+    }
+    /// clone the rows of the vector into those satisfying `pred` and those that don't, keeping
+    /// their order
+    pub fn partition_rows<F: Fn(&[T]) -> bool>(&self, pred: F) -> (Vec<Vec<T>>, Vec<Vec<T>>) {
+        self.row_slices()
+            .map(|row| row.to_vec())
+            .partition(|row| pred(row))
+    }
+    /// create a clone of this vector with its rows and columns swapped
+    pub fn transpose(&self) -> V2<T, N_COLS, N_ROWS> {
+        let mut data = Vec::with_capacity(self.data.len());
+        for col_ix in 0..N_COLS {
+            for row_ix in 0..N_ROWS {
+                data.push(self.data[row_ix * N_COLS + col_ix].clone());
+            }
+        }
+        V2 { data }
+    }
+    /// write this vector with its rows and columns swapped into `out`, overwriting its contents
+    pub fn transpose_into(&self, out: &mut V2<T, N_COLS, N_ROWS>) {
+        for row_ix in 0..N_ROWS {
+            for col_ix in 0..N_COLS {
+                out.data[col_ix * N_ROWS + row_ix].clone_from(&self.data[row_ix * N_COLS + col_ix]);
+            }
+        }
+    }
+    /// create a clone of this vector rotated 90 degrees clockwise
+    pub fn rotate_cw(&self) -> V2<T, N_COLS, N_ROWS> {
+        let mut data = Vec::with_capacity(self.data.len());
+        for col_ix in 0..N_COLS {
+            for row_ix in (0..N_ROWS).rev() {
+                data.push(self.data[row_ix * N_COLS + col_ix].clone());
+            }
+        }
+        V2 { data }
+    }
+    /// create a clone of this vector rotated 90 degrees counterclockwise
+    pub fn rotate_ccw(&self) -> V2<T, N_COLS, N_ROWS> {
+        let mut data = Vec::with_capacity(self.data.len());
+        for col_ix in (0..N_COLS).rev() {
+            for row_ix in 0..N_ROWS {
+                data.push(self.data[row_ix * N_COLS + col_ix].clone());
+            }
+        }
+        V2 { data }
+    }
+    /// clone the vector into an `Arc`, for sharing read-only grid state across threads
+    ///
+    /// each thread gets its own `Arc::clone` of the snapshot; writes to `self` after the
+    /// snapshot is taken are not visible through it
+    pub fn snapshot(&self) -> Arc<V2<T, N_ROWS, N_COLS>> {
+        Arc::new(self.clone())
+    }
+    /// overwrite every cell with `value`
+    pub fn fill(&mut self, value: T) {
+        self.data.fill(value);
+    }
+    /// replace the value of `start` and of every cell connected to it through cardinal neighbors
+    /// holding the same value with `new_value`
+    pub fn flood_fill(&mut self, start: BoundedIx2<N_ROWS, N_COLS>, new_value: T)
+    where
+        T: PartialEq,
+    {
+        let target = self[start].clone();
+        let mut visited = HashSet::from([start]);
+        let mut queue = VecDeque::from([start]);
+        while let Some(ix) = queue.pop_front() {
+            for n in Ix2CardinalNeighbors::new(ix) {
+                if self[n] == target && visited.insert(n) {
+                    queue.push_back(n);
+                }
+            }
+            self[ix] = new_value.clone();
+        }
+    }
+    /// cyclically shift the values in the `ring`th concentric border of the vector (the outer
+    /// border is ring 0) `steps` positions clockwise, or counterclockwise if `steps` is negative
+    ///
+    /// does nothing if there's no such ring
+    pub fn rotate_ring(&mut self, ring: usize, steps: isize) {
+        let ixs = Self::ring_indices(ring);
+        if ixs.is_empty() {
+            return;
+        }
+        let values: Vec<T> = ixs.iter().map(|ix| self[*ix].clone()).collect();
+        let shift = steps.rem_euclid(ixs.len() as isize) as usize;
+        for (i, ix) in ixs.iter().enumerate() {
+            self[*ix] = values[(i + ixs.len() - shift) % ixs.len()].clone();
+        }
+    }
+    /// possibly clone the `SR`x`SC` block whose top-left corner is `top_left`, returning `None`
+    /// if the block doesn't fit inside the vector
+    pub fn submatrix<const SR: usize, const SC: usize>(
+        &self,
+        top_left: BoundedIx2<N_ROWS, N_COLS>,
+    ) -> Option<V2<T, SR, SC>> {
+        let (top, left) = (top_left.y(), top_left.x());
+        if top + SR > N_ROWS || left + SC > N_COLS {
+            return None;
+        }
+        let data = self
+            .row_slices()
+            .skip(top)
+            .take(SR)
+            .flat_map(|row| row[left..left + SC].iter().cloned())
+            .collect();
+        Some(V2 { data })
+    }
+}
+
+impl<const N_ROWS: usize, const N_COLS: usize> V2<f64, N_ROWS, N_COLS> {
+    /// possibly find the `(row, col)` center of mass of the vector, treating each value as the
+    /// weight of its cell
+    ///
+    /// returns `None` if the total weight is zero
+    pub fn centroid(&self) -> Option<(f64, f64)> {
+        let mut total = 0.0;
+        let (mut row_sum, mut col_sum) = (0.0, 0.0);
+        for (ix, w) in V2Indices::<N_ROWS, N_COLS>::new().zip(self.data.iter()) {
+            total += w;
+            row_sum += w * ix.y() as f64;
+            col_sum += w * ix.x() as f64;
+        }
+        if total == 0.0 {
+            None
+        } else {
+            Some((row_sum / total, col_sum / total))
+        }
+    }
+    /// one Jacobi relaxation step: create a new vector where each cell is the average of its
+    /// cardinal neighbors, except for boundary cells, which are set to the value returned by
+    /// `boundary`
+    ///
+    /// a neighbor outside the vector is replaced by the current value of the cell itself
+    pub fn jacobi_step(
+        &self,
+        boundary: impl Fn(BoundedIx2<N_ROWS, N_COLS>) -> Option<f64>,
+    ) -> V2<f64, N_ROWS, N_COLS> {
+        self.map_indexed(|ix, v| {
+            boundary(ix).unwrap_or_else(|| {
+                let sum: f64 = [ix.north(), ix.east(), ix.south(), ix.west()]
+                    .into_iter()
+                    .map(|n| n.map_or(*v, |n| self[n]))
+                    .sum();
+                sum / 4.0
+            })
+        })
+    }
+}
+
+impl<const N_ROWS: usize, const N_COLS: usize> V2<bool, N_ROWS, N_COLS> {
+    /// run `generations` generations of Conway's game of life (B3/S23), treating cells outside
+    /// the vector as dead
+    pub fn run_life(&self, generations: usize) -> V2<bool, N_ROWS, N_COLS> {
+        let mut state = self.clone();
+        for _ in 0..generations {
+            state = state.life_step();
+        }
+        state
+    }
+    /// like `run_life`, but stop early once a generation is identical to the one before it
+    pub fn run_life_until_stable(&self, max_steps: usize) -> V2<bool, N_ROWS, N_COLS> {
+        let mut state = self.clone();
+        for _ in 0..max_steps {
+            let next = state.life_step();
+            if next == state {
+                break;
+            }
+            state = next;
+        }
+        state
+    }
+    /// a single game of life generation
+    fn life_step(&self) -> V2<bool, N_ROWS, N_COLS> {
+        self.map_indexed(|ix, alive| {
+            let live_neighbors = Ix2Neighbors::new(ix).filter(|n| self[*n]).count();
+            matches!((alive, live_neighbors), (true, 2) | (_, 3))
+        })
+    }
+}
+
+impl<const N_ROWS: usize, const N_COLS: usize> V2<u8, N_ROWS, N_COLS> {
+    /// sum the risk levels (height plus one) of the local minima (see `local_minima`)
+    pub fn sum_risk(&self) -> u64 {
+        self.local_minima()
+            .into_iter()
+            .map(|ix| self[ix] as u64 + 1)
+            .sum()
+    }
+    /// sizes of the basins around each local minimum (see `local_minima`), largest first
+    ///
+    /// a basin is every cell reachable from its minimum through cardinal neighbors without
+    /// crossing a cell of height 9
+    pub fn basin_sizes(&self) -> Vec<usize> {
+        let mut sizes: Vec<usize> = self
+            .local_minima()
+            .into_iter()
+            .map(|ix| {
+                self.bfs_distances(ix, |h| *h != 9)
+                    .into_iter()
+                    .filter(Option::is_some)
+                    .count()
+            })
+            .collect();
+        sizes.sort_by(|a, b| b.cmp(a));
+        sizes
+    }
+}
+
+impl<const N_ROWS: usize, const N_COLS: usize> V2<[u8; 3], N_ROWS, N_COLS> {
+    /// encode the vector as a binary (P6) PPM image, one RGB pixel per cell
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut ppm = format!("P6\n{N_COLS} {N_ROWS}\n255\n").into_bytes();
+        ppm.extend(self.data.iter().flatten());
+        ppm
+    }
+    /// decode a binary (P6) PPM image with a maximum color value of at most 255
+    ///
+    /// errors if the header is malformed, its dimensions don't match the vector's, or the pixel
+    /// data is too short
+    pub fn from_ppm(bytes: &[u8]) -> Result<Self, VError> {
+        let mut pos = 0;
+        if ppm_token(bytes, &mut pos)? != b"P6" {
+            return Err(VError::ParseError("not a binary PPM image".to_string()));
+        }
+        let width = ppm_number(bytes, &mut pos)?;
+        let height = ppm_number(bytes, &mut pos)?;
+        let max_val = ppm_number(bytes, &mut pos)?;
+        if width != N_COLS {
+            return Err(VError::SizingError {
+                expected: N_COLS,
+                actual: width,
+            });
+        }
+        if height != N_ROWS {
+            return Err(VError::SizingError {
+                expected: N_ROWS,
+                actual: height,
+            });
+        }
+        if max_val == 0 || max_val > 255 {
+            return Err(VError::ParseError(format!(
+                "unsupported maximum color value {max_val}"
+            )));
+        }
+        // exactly one whitespace byte separates the header from the pixel data
+        let pixels = bytes.get(pos + 1..).unwrap_or_default();
+        if pixels.len() < N_ROWS * N_COLS * 3 {
+            Err(VError::SizingError {
+                expected: N_ROWS * N_COLS * 3,
+                actual: pixels.len(),
+            })
+        } else {
+            Ok(Self {
+                data: pixels
+                    .chunks_exact(3)
+                    .take(N_ROWS * N_COLS)
+                    .map(|p| [p[0], p[1], p[2]])
+                    .collect(),
+            })
+        }
+    }
+}
+
+/// read the next whitespace-delimited token of a PPM header, skipping comments
+fn ppm_token<'a>(bytes: &'a [u8], pos: &mut usize) -> Result<&'a [u8], VError> {
+    loop {
+        match bytes.get(*pos) {
+            Some(b) if b.is_ascii_whitespace() => *pos += 1,
+            Some(b'#') => {
+                while bytes.get(*pos).is_some_and(|b| *b != b'\n') {
+                    *pos += 1;
+                }
+            }
+            Some(_) => break,
+            None => return Err(VError::ParseError("truncated PPM header".to_string())),
+        }
+    }
+    let start = *pos;
+    while bytes.get(*pos).is_some_and(|b| !b.is_ascii_whitespace()) {
+        *pos += 1;
+    }
+    Ok(&bytes[start..*pos])
+}
+
+/// read the next token of a PPM header as a number
+fn ppm_number(bytes: &[u8], pos: &mut usize) -> Result<usize, VError> {
+    let token = ppm_token(bytes, pos)?;
+    std::str::from_utf8(token)
+        .ok()
+        .and_then(|t| t.parse().ok())
+        .ok_or_else(|| {
+            VError::ParseError(format!(
+                "invalid PPM header field {:?}",
+                String::from_utf8_lossy(token)
+            ))
+        })
+}
+
+#[cfg(feature = "rayon")]
+impl<T, const N_ROWS: usize, const N_COLS: usize> V2<T, N_ROWS, N_COLS>
+where
+    T: Send + Sync,
+{
+    /// create a new vector by applying `f` to each cell and its in-bounds neighbors,
+    /// computing rows in parallel
+    ///
+    /// neighbors are passed in the same order as `Ix2Neighbors` yields them
+    pub fn par_apply_stencil<F: Fn(&T, &[&T]) -> T + Sync>(&self, f: F) -> V2<T, N_ROWS, N_COLS> {
+        use rayon::prelude::*;
+        let f = &f;
+        let data = (0..N_ROWS)
+            .into_par_iter()
+            .flat_map_iter(|row_ix| {
+                (0..N_COLS).map(move |col_ix| {
+                    let ix = BoundedIx2::new(row_ix, col_ix).unwrap();
+                    let neighbors: Vec<&T> = Ix2Neighbors::new(ix).map(|n| &self[n]).collect();
+                    f(&self[ix], &neighbors)
+                })
+            })
+            .collect();
+        V2 { data }
+    }
+}
+
+impl<T, const N_ROWS: usize, const N_COLS: usize> std::fmt::Debug for V2<T, N_ROWS, N_COLS>
+where
+    T: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "V2<{}, {}> {{ data: {:?} }}", N_ROWS, N_COLS, self.data)
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl<T, const N_ROWS: usize, const N_COLS: usize> V2<T, N_ROWS, N_COLS>
+where
+    T: num_traits::CheckedAdd,
+{
+    /// possibly create a new vector by adding the corresponding cells of two vectors,
+    /// returning `None` if any sum overflows
+    pub fn checked_add(&self, other: &V2<T, N_ROWS, N_COLS>) -> Option<V2<T, N_ROWS, N_COLS>> {
+        let data = self
+            .data
+            .iter()
+            .zip(other.data.iter())
+            .map(|(a, b)| a.checked_add(b))
+            .collect::<Option<Vec<T>>>()?;
+        Some(V2 { data })
+    }
+}
+
+impl<T, const N_ROWS: usize, const N_COLS: usize> V2<T, N_ROWS, N_COLS>
+where
+    T: std::fmt::Debug,
+{
+    /// a `Debug` representation with each row on its own line and cells right-aligned
+    pub fn debug_grid(&self) -> impl std::fmt::Debug + '_ {
+        DebugGrid(self)
+    }
+}
+
+struct DebugGrid<'a, T, const N_ROWS: usize, const N_COLS: usize>(&'a V2<T, N_ROWS, N_COLS>);
+
+impl<T, const N_ROWS: usize, const N_COLS: usize> std::fmt::Debug
+    for DebugGrid<'_, T, N_ROWS, N_COLS>
+where
+    T: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let cells: Vec<String> = self.0.data.iter().map(|v| format!("{v:?}")).collect();
+        let width = cells.iter().map(|c| c.chars().count()).max().unwrap_or(0);
+        for (i, cell) in cells.iter().enumerate() {
+            if i > 0 {
+                if i % N_COLS == 0 {
+                    writeln!(f)?;
+                } else {
+                    write!(f, " ")?;
+                }
+            }
+            write!(f, "{cell:>width$}")?;
+        }
+        Ok(())
+    }
+}
+
+/// how `V2::convolve` reads cells outside the vector
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum EdgeMode {
+    /// cells outside the vector are 0
+    Zero,
+    /// cells outside the vector take the value of the nearest cell inside it
+    Clamp,
+    /// cells outside the vector wrap around to the opposite edge
+    Wrap,
+}
+
+/// a path cost, totally ordered so it can be used as a priority
+#[derive(PartialEq)]
+struct Cost(f64);
+
+impl Eq for Cost {}
+
+impl PartialOrd for Cost {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Cost {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl<T, const N_ROWS: usize, const N_COLS: usize> Clone for V2<T, N_ROWS, N_COLS>
+where
+    T: Clone,
 {
     fn clone(&self) -> Self {
         Self {
@@ -187,4 +2001,1352 @@ mod tests {
         let actual = format!("{v}");
         assert_eq!(expected, actual);
     }
+    #[test]
+    fn test_scenic_score() {
+        let v: V2<u8, 5, 5> = V2::new(vec![
+            3, 0, 3, 7, 3, 2, 5, 5, 1, 2, 6, 5, 3, 3, 2, 3, 3, 5, 4, 9, 3, 5, 3, 9, 0,
+        ])
+        .unwrap();
+        assert_eq!(4, v.scenic_score(BoundedIx2::new(1, 2).unwrap()));
+        assert_eq!(8, v.scenic_score(BoundedIx2::new(3, 2).unwrap()));
+        assert_eq!(0, v.scenic_score(BoundedIx2::new(0, 3).unwrap()));
+        assert_eq!(0, v.scenic_score(BoundedIx2::new(2, 4).unwrap()));
+    }
+    #[test]
+    fn test_iter_region_mut() {
+        let mut v: V2<u8, 4, 4> = V2::new((0..16).collect()).unwrap();
+        let top_left = BoundedIx2::new(1, 1).unwrap();
+        let bottom_right = BoundedIx2::new(2, 2).unwrap();
+        let mut seen = Vec::new();
+        for (ix, cell) in v.iter_region_mut(top_left, bottom_right).unwrap() {
+            seen.push(ix);
+            *cell += 100;
+        }
+        assert_eq!(
+            vec![
+                BoundedIx2::new(1, 1).unwrap(),
+                BoundedIx2::new(1, 2).unwrap(),
+                BoundedIx2::new(2, 1).unwrap(),
+                BoundedIx2::new(2, 2).unwrap(),
+            ],
+            seen
+        );
+        let expected = vec![0, 1, 2, 3, 4, 105, 106, 7, 8, 109, 110, 11, 12, 13, 14, 15];
+        assert_eq!(expected, v.data);
+        assert!(v.iter_region_mut(bottom_right, top_left).is_none());
+    }
+    #[test]
+    fn test_from_strided() {
+        let src: Vec<u8> = (0..8).collect();
+        let v: V2<u8, 2, 2> = V2::from_strided(&src, 4).unwrap();
+        assert_eq!(vec![0, 1, 4, 5], v.data);
+        assert!(matches!(
+            V2::<u8, 2, 2>::from_strided(&src, 1),
+            Err(VError::SizingError {
+                expected: 2,
+                actual: 1
+            })
+        ));
+        assert!(matches!(
+            V2::<u8, 2, 2>::from_strided(&src[..5], 4),
+            Err(VError::SizingError {
+                expected: 6,
+                actual: 5
+            })
+        ));
+        assert!(matches!(
+            V2::<u8, 2, 2>::from_strided(&[0; 8], usize::MAX),
+            Err(VError::SizingError {
+                expected: usize::MAX,
+                actual: 8
+            })
+        ));
+    }
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_apply_stencil() {
+        use crate::ix::iterators::{Ix2Neighbors, V2Indices};
+        let v: V2<u32, 64, 48> = V2::new((0..64 * 48).collect()).unwrap();
+        let f = |c: &u32, ns: &[&u32]| ns.iter().fold(*c, |acc, n| acc.wrapping_mul(31) ^ **n);
+        let expected: Vec<u32> = V2Indices::<64, 48>::new()
+            .map(|ix| {
+                let ns: Vec<&u32> = Ix2Neighbors::new(ix).map(|n| &v[n]).collect();
+                f(&v[ix], &ns)
+            })
+            .collect();
+        let actual = v.par_apply_stencil(f);
+        assert_eq!(expected, actual.data);
+    }
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_apply_stencil_matches_map_with_neighbors() {
+        let v: V2<u32, 64, 48> = V2::new((0..64 * 48).collect()).unwrap();
+        let f = |c: &u32, ns: &[&u32]| ns.iter().fold(*c, |acc, n| acc.wrapping_mul(31) ^ **n);
+        assert_eq!(v.map_with_neighbors(f), v.par_apply_stencil(f));
+    }
+    #[test]
+    fn test_count_enclosed() {
+        let enclosed: V2<char, 5, 5> =
+            V2::new("######..###..#.#####.....".chars().collect()).unwrap();
+        assert_eq!(4, enclosed.count_enclosed(|c| *c == '#'));
+        let open: V2<char, 5, 5> = V2::new("######..###....#####.....".chars().collect()).unwrap();
+        assert_eq!(0, open.count_enclosed(|c| *c == '#'));
+    }
+    #[test]
+    fn test_debug_grid() {
+        let v: V2<u8, 2, 2> = V2::new(vec![1, 10, 100, 2]).unwrap();
+        assert_eq!("  1  10\n100   2", format!("{:?}", v.debug_grid()));
+        assert_eq!("V2<2, 2> { data: [1, 10, 100, 2] }", format!("{v:?}"));
+    }
+    #[test]
+    fn test_hex_neighbors() {
+        let v: V2<u8, 4, 4> = V2::new((0..16).collect()).unwrap();
+        let even: Vec<u8> = v
+            .hex_neighbors(BoundedIx2::new(2, 1).unwrap())
+            .map(|(_, n)| *n)
+            .collect();
+        assert_eq!(vec![4, 5, 8, 10, 12, 13], even);
+        let odd: Vec<u8> = v
+            .hex_neighbors(BoundedIx2::new(1, 1).unwrap())
+            .map(|(_, n)| *n)
+            .collect();
+        assert_eq!(vec![1, 2, 4, 6, 9, 10], odd);
+        let edge: Vec<BoundedIx2<4, 4>> = v
+            .hex_neighbors(BoundedIx2::new(1, 3).unwrap())
+            .map(|(ix, _)| ix)
+            .collect();
+        assert_eq!(
+            vec![
+                BoundedIx2::new(0, 3).unwrap(),
+                BoundedIx2::new(1, 2).unwrap(),
+                BoundedIx2::new(2, 3).unwrap(),
+            ],
+            edge
+        );
+    }
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn test_checked_add() {
+        let a: V2<u8, 2, 2> = V2::new(vec![1, 2, 3, 200]).unwrap();
+        let b: V2<u8, 2, 2> = V2::new(vec![10, 20, 30, 55]).unwrap();
+        assert_eq!(vec![11, 22, 33, 255], a.checked_add(&b).unwrap().data);
+        let c: V2<u8, 2, 2> = V2::new(vec![0, 0, 0, 56]).unwrap();
+        assert!(a.checked_add(&c).is_none());
+    }
+    #[test]
+    fn test_argsort() {
+        let distinct: V2<u8, 2, 2> = V2::new(vec![4, 1, 3, 2]).unwrap();
+        assert_eq!(
+            vec![
+                BoundedIx2::new(0, 1).unwrap(),
+                BoundedIx2::new(1, 1).unwrap(),
+                BoundedIx2::new(1, 0).unwrap(),
+                BoundedIx2::new(0, 0).unwrap(),
+            ],
+            distinct.argsort()
+        );
+        let tied: V2<u8, 2, 3> = V2::new(vec![2, 1, 2, 1, 0, 2]).unwrap();
+        assert_eq!(
+            vec![
+                BoundedIx2::new(1, 1).unwrap(),
+                BoundedIx2::new(0, 1).unwrap(),
+                BoundedIx2::new(1, 0).unwrap(),
+                BoundedIx2::new(0, 0).unwrap(),
+                BoundedIx2::new(0, 2).unwrap(),
+                BoundedIx2::new(1, 2).unwrap(),
+            ],
+            tied.argsort()
+        );
+    }
+    #[test]
+    fn test_fill_basins() {
+        let v: V2<u8, 3, 5> = V2::new(vec![1, 2, 5, 2, 1, 2, 3, 5, 3, 2, 3, 4, 5, 4, 3]).unwrap();
+        let expected = vec![0, 0, 1, 1, 1, 0, 0, 1, 1, 1, 0, 0, 1, 1, 1];
+        assert_eq!(expected, v.fill_basins().data);
+    }
+    #[test]
+    fn test_trace_contour() {
+        let mut v: V2<bool, 5, 6> = V2::default();
+        for row_ix in 1..=3 {
+            for col_ix in 1..=4 {
+                v[BoundedIx2::new(row_ix, col_ix).unwrap()] = true;
+            }
+        }
+        let expected: Vec<BoundedIx2<5, 6>> = [
+            (1, 1),
+            (1, 2),
+            (1, 3),
+            (1, 4),
+            (2, 4),
+            (3, 4),
+            (3, 3),
+            (3, 2),
+            (3, 1),
+            (2, 1),
+        ]
+        .into_iter()
+        .map(|(r, c)| BoundedIx2::new(r, c).unwrap())
+        .collect();
+        assert_eq!(
+            expected,
+            v.trace_contour(BoundedIx2::new(1, 1).unwrap(), |c| *c)
+                .unwrap()
+        );
+        assert!(
+            v.trace_contour(BoundedIx2::new(2, 2).unwrap(), |c| *c)
+                .is_none()
+        );
+        assert!(
+            v.trace_contour(BoundedIx2::new(0, 0).unwrap(), |c| *c)
+                .is_none()
+        );
+    }
+    #[test]
+    fn test_settle_down() {
+        let v: V2<char, 4, 2> = V2::new("ax.ybz.w".chars().collect()).unwrap();
+        let settled = v.settle_down(|c| *c == '.', '.');
+        assert_eq!(".x.yazbw".chars().collect::<Vec<char>>(), settled.data);
+    }
+    #[test]
+    fn test_reachable_within() {
+        let open: V2<char, 5, 5> = V2::new(vec!['.'; 25]).unwrap();
+        let center = BoundedIx2::new(2, 2).unwrap();
+        assert_eq!(1, open.reachable_within(center, 0, |c| *c == '.'));
+        assert_eq!(5, open.reachable_within(center, 1, |c| *c == '.'));
+        assert_eq!(13, open.reachable_within(center, 2, |c| *c == '.'));
+        let walled: V2<char, 3, 4> = V2::new("..#..##.....".chars().collect()).unwrap();
+        let corner = BoundedIx2::new(0, 0).unwrap();
+        assert_eq!(1, walled.reachable_within(corner, 0, |c| *c == '.'));
+        assert_eq!(3, walled.reachable_within(corner, 1, |c| *c == '.'));
+        assert_eq!(5, walled.reachable_within(corner, 3, |c| *c == '.'));
+        assert_eq!(9, walled.reachable_within(corner, 10, |c| *c == '.'));
+    }
+    #[test]
+    fn test_reachable_exactly() {
+        let walled: V2<char, 3, 4> = V2::new("..#..##.....".chars().collect()).unwrap();
+        let corner = BoundedIx2::new(0, 0).unwrap();
+        assert_eq!(1, walled.reachable_exactly(corner, 0, |c| *c == '.'));
+        assert_eq!(2, walled.reachable_exactly(corner, 2, |c| *c == '.'));
+        assert_eq!(3, walled.reachable_exactly(corner, 3, |c| *c == '.'));
+        assert_eq!(5, walled.reachable_exactly(corner, 7, |c| *c == '.'));
+    }
+    #[test]
+    fn test_block_reduce() {
+        let v: V2<f64, 4, 4> = V2::new((0..16).map(f64::from).collect()).unwrap();
+        let mean: V2<f64, 2, 2> =
+            v.block_reduce::<2, 2, _, _>(|b| b.iter().copied().sum::<f64>() / b.len() as f64);
+        assert_eq!(vec![2.5, 4.5, 10.5, 12.5], mean.data);
+        let max: V2<f64, 2, 2> =
+            v.block_reduce::<2, 2, _, _>(|b| b.iter().copied().fold(f64::MIN, |a, c| a.max(*c)));
+        assert_eq!(vec![5.0, 7.0, 13.0, 15.0], max.data);
+    }
+    #[test]
+    fn test_upscale() {
+        let v: V2<u8, 2, 2> = V2::new(vec![1, 2, 3, 4]).unwrap();
+        let up: V2<u8, 4, 4> = v.upscale::<2, 2>();
+        let expected = vec![1, 1, 2, 2, 1, 1, 2, 2, 3, 3, 4, 4, 3, 3, 4, 4];
+        assert_eq!(expected, up.data);
+        let wide: V2<u8, 2, 6> = v.upscale::<1, 3>();
+        assert_eq!(vec![1, 1, 1, 2, 2, 2, 3, 3, 3, 4, 4, 4], wide.data);
+    }
+    #[test]
+    fn test_partition_rows() {
+        let v: V2<u8, 3, 3> = V2::new((0..=8).collect()).unwrap();
+        let (even, odd) = v.partition_rows(|row| row.iter().sum::<u8>() % 2 == 0);
+        assert_eq!(vec![vec![3, 4, 5]], even);
+        assert_eq!(vec![vec![0, 1, 2], vec![6, 7, 8]], odd);
+    }
+    #[test]
+    fn test_flat_index() {
+        let v: V2<u8, 3, 4> = V2::new((0..12).collect()).unwrap();
+        for flat in 0..12 {
+            let ix = V2::<u8, 3, 4>::index_from_flat(flat);
+            assert!(ix.is_some());
+            assert_eq!(Some(flat), v.flat_index(ix));
+            assert_eq!(flat as u8, v[ix.unwrap()]);
+        }
+        assert!(V2::<u8, 3, 4>::index_from_flat(12).is_none());
+        let bottom_right = BoundedIx2::new(2, 3).unwrap();
+        assert_eq!(Some(11), v.flat_index(Some(bottom_right)));
+        assert!(v.flat_index(bottom_right.south()).is_none());
+        assert!(v.flat_index(bottom_right.east()).is_none());
+    }
+    #[test]
+    fn test_row_bands() {
+        let v: V2<u8, 3, 4> = V2::new((0..12).collect()).unwrap();
+        let bands: Vec<Vec<u8>> = v
+            .row_bands::<2>()
+            .map(|band| band.data.into_iter().copied().collect())
+            .collect();
+        assert_eq!(
+            vec![vec![0, 1, 2, 3, 4, 5, 6, 7], vec![4, 5, 6, 7, 8, 9, 10, 11]],
+            bands
+        );
+        assert_eq!(0, v.row_bands::<4>().count());
+    }
+    #[test]
+    fn test_cardinal_neighbors_south() {
+        let v: V2<u8, 3, 3> = V2::new((0..=8).collect()).unwrap();
+        let center = BoundedIx2::new(0, 1).unwrap();
+        let actual: Vec<u8> = Ix2CardinalNeighbors::new(center).map(|n| v[n]).collect();
+        assert_eq!(vec![2, 4, 0], actual);
+        assert_eq!(Some(&4), v.get(center.south()));
+    }
+    #[test]
+    fn test_centroid() {
+        let mut single: V2<f64, 3, 4> = V2::default();
+        assert!(single.centroid().is_none());
+        single[BoundedIx2::new(2, 1).unwrap()] = 5.0;
+        assert_eq!(Some((2.0, 1.0)), single.centroid());
+        let symmetric: V2<f64, 3, 3> =
+            V2::new(vec![1.0, 2.0, 1.0, 2.0, 4.0, 2.0, 1.0, 2.0, 1.0]).unwrap();
+        assert_eq!(Some((1.0, 1.0)), symmetric.centroid());
+    }
+    #[test]
+    fn test_grid_adjacency() {
+        let v: V2<u8, 2, 2> = V2::new(vec![1, 1, 2, 1]).unwrap();
+        let adjacency: V2<bool, 4, 4> = v.grid_adjacency(|a, b| a == b);
+        let expected = vec![
+            false, true, false, false, true, false, false, true, false, false, false, false, false,
+            true, false, false,
+        ];
+        assert_eq!(expected, adjacency.data);
+    }
+    #[test]
+    fn test_cells_with_wrapped_neighbors() {
+        let v: V2<u8, 3, 3> = V2::new((0..=8).collect()).unwrap();
+        let cells: Vec<(BoundedIx2<3, 3>, &u8, [&u8; 8])> =
+            v.cells_with_wrapped_neighbors().collect();
+        assert_eq!(9, cells.len());
+        let (ix, value, neighbors) = cells[0];
+        assert_eq!(BoundedIx2::new(0, 0).unwrap(), ix);
+        assert_eq!(0, *value);
+        assert_eq!([8, 6, 7, 2, 1, 5, 3, 4], neighbors.map(|n| *n));
+        let (_, value, neighbors) = cells[8];
+        assert_eq!(8, *value);
+        assert_eq!([4, 5, 3, 7, 6, 1, 2, 0], neighbors.map(|n| *n));
+    }
+    #[test]
+    fn test_degree_grid() {
+        let square: V2<(), 3, 3> = V2::default();
+        assert_eq!(vec![3, 5, 3, 5, 8, 5, 3, 5, 3], square.degree_grid().data);
+        let line: V2<(), 1, 4> = V2::default();
+        assert_eq!(vec![1, 2, 2, 1], line.degree_grid().data);
+    }
+    #[test]
+    fn test_map() {
+        let v: V2<u8, 2, 3> = V2::new((0..6).collect()).unwrap();
+        let mapped: V2<i32, 2, 3> = v.map(|x| *x as i32 * 2);
+        assert_eq!(vec![0, 2, 4, 6, 8, 10], mapped.data);
+        assert_eq!(4, mapped[BoundedIx2::new(0, 2).unwrap()]);
+        assert_eq!(6, mapped[BoundedIx2::new(1, 0).unwrap()]);
+    }
+    #[test]
+    fn test_into_rows() {
+        let v: V2<u8, 3, 3> = V2::new((0..=8).collect()).unwrap();
+        let rows: Vec<Vec<u8>> = v.into_rows().collect();
+        assert_eq!(vec![vec![0, 1, 2], vec![3, 4, 5], vec![6, 7, 8]], rows);
+        #[derive(Debug, PartialEq)]
+        struct NoClone(u8);
+        let v: V2<NoClone, 2, 2> = V2::new((0..4).map(NoClone).collect()).unwrap();
+        let rows: Vec<Vec<NoClone>> = v.into_rows().collect();
+        assert_eq!(
+            vec![vec![NoClone(0), NoClone(1)], vec![NoClone(2), NoClone(3)]],
+            rows
+        );
+    }
+    #[test]
+    fn test_map_indexed() {
+        let v: V2<(), 2, 2> = V2::default();
+        let mapped: V2<usize, 2, 2> = v.map_indexed(|ix, _| ix.y() * 2 + ix.x());
+        assert_eq!(vec![0, 1, 2, 3], mapped.data);
+        for ix in V2Indices::<2, 2>::new() {
+            assert_eq!(ix.as_usize(), mapped[ix]);
+        }
+    }
+    #[test]
+    fn test_mul_vec() {
+        let m: V2<i32, 2, 3> = V2::new(vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!([14, 32], m.mul_vec(&[1, 2, 3]));
+        assert_eq!([-1, -1], m.mul_vec(&[1, -1, 0]));
+    }
+    #[test]
+    fn test_transpose() {
+        let v: V2<u8, 2, 3> = V2::new((0..6).collect()).unwrap();
+        let t: V2<u8, 3, 2> = v.transpose();
+        assert_eq!(vec![0, 3, 1, 4, 2, 5], t.data);
+        assert_eq!(
+            v[BoundedIx2::new(1, 2).unwrap()],
+            t[BoundedIx2::new(2, 1).unwrap()]
+        );
+        assert_eq!(v, t.transpose());
+    }
+    #[test]
+    fn test_rotate() {
+        let v: V2<u8, 2, 3> = V2::new((0..6).collect()).unwrap();
+        let cw: V2<u8, 3, 2> = v.rotate_cw();
+        assert_eq!(vec![3, 0, 4, 1, 5, 2], cw.data);
+        let ccw: V2<u8, 3, 2> = v.rotate_ccw();
+        assert_eq!(vec![2, 5, 1, 4, 0, 3], ccw.data);
+        assert_eq!(v, cw.rotate_ccw());
+        let square: V2<u8, 3, 3> = V2::new((0..=8).collect()).unwrap();
+        assert_eq!(
+            square,
+            square.rotate_cw().rotate_cw().rotate_cw().rotate_cw()
+        );
+    }
+    #[test]
+    fn test_values_along() {
+        let v: V2<u8, 3, 3> = V2::new((0..=8).collect()).unwrap();
+        let path: Vec<Option<BoundedIx2<3, 3>>> = [(0, 0), (0, 1), (1, 1), (2, 1), (2, 2)]
+            .into_iter()
+            .map(|(r, c)| BoundedIx2::new(r, c))
+            .collect();
+        assert_eq!(Some(vec![&0, &1, &4, &7, &8]), v.values_along(&path));
+        assert_eq!(Some(vec![]), v.values_along(&[]));
+        let off_grid = [BoundedIx2::new(0, 0), BoundedIx2::new(0, 3)];
+        assert_eq!(None, v.values_along(&off_grid));
+    }
+    #[test]
+    fn test_widest_path() {
+        let v: V2<u8, 3, 3> = V2::new(vec![5, 1, 5, 5, 1, 5, 3, 9, 5]).unwrap();
+        let start = BoundedIx2::new(0, 0).unwrap();
+        assert_eq!(
+            Some(3),
+            v.widest_path(start, BoundedIx2::new(0, 2).unwrap())
+        );
+        assert_eq!(
+            Some(5),
+            v.widest_path(start, BoundedIx2::new(1, 0).unwrap())
+        );
+        assert_eq!(Some(5), v.widest_path(start, start));
+    }
+    #[test]
+    fn test_flip() {
+        let mut square: V2<u8, 3, 3> = V2::new((0..=8).collect()).unwrap();
+        square.flip_horizontal();
+        assert_eq!(vec![2, 1, 0, 5, 4, 3, 8, 7, 6], square.data);
+        square.flip_vertical();
+        assert_eq!(vec![8, 7, 6, 5, 4, 3, 2, 1, 0], square.data);
+        let mut wide: V2<u8, 2, 4> = V2::new((0..8).collect()).unwrap();
+        wide.flip_horizontal();
+        assert_eq!(vec![3, 2, 1, 0, 7, 6, 5, 4], wide.data);
+        wide.flip_vertical();
+        assert_eq!(vec![7, 6, 5, 4, 3, 2, 1, 0], wide.data);
+    }
+    #[test]
+    fn test_jacobi_step() {
+        // fixed edges of a linear ramp, which is harmonic, with a zeroed interior
+        let edge = |ix: BoundedIx2<3, 4>| {
+            if ix.y() == 0 || ix.y() == 2 || ix.x() == 0 || ix.x() == 3 {
+                Some(ix.x() as f64)
+            } else {
+                None
+            }
+        };
+        let v: V2<f64, 3, 4> = V2::new(vec![0.0; 12])
+            .unwrap()
+            .map_indexed(|ix, v| edge(ix).unwrap_or(*v));
+        let one = v.jacobi_step(edge);
+        assert_eq!(
+            vec![0.0, 1.0, 2.0, 3.0, 0.0, 0.5, 1.75, 3.0, 0.0, 1.0, 2.0, 3.0],
+            one.data
+        );
+        let converged = (0..50).fold(one, |acc, _| acc.jacobi_step(edge));
+        for (actual, expected) in converged
+            .data
+            .iter()
+            .zip([0.0, 1.0, 2.0, 3.0].iter().cycle())
+        {
+            assert!((actual - expected).abs() < 1e-9);
+        }
+    }
+    #[test]
+    fn test_iter() {
+        let mut v: V2<u8, 2, 3> = V2::new((0..6).collect()).unwrap();
+        assert_eq!(vec![&0, &1, &2, &3, &4, &5], v.iter().collect::<Vec<&u8>>());
+        for x in v.iter_mut() {
+            *x *= 2;
+        }
+        assert_eq!(vec![0, 2, 4, 6, 8, 10], v.data);
+    }
+    #[test]
+    fn test_to_ppm() {
+        let v: V2<[u8; 3], 2, 2> =
+            V2::new(vec![[255, 0, 0], [0, 255, 0], [0, 0, 255], [1, 2, 3]]).unwrap();
+        let ppm = v.to_ppm();
+        let header = b"P6\n2 2\n255\n";
+        assert_eq!(header, &ppm[..header.len()]);
+        assert_eq!(
+            &[255, 0, 0, 0, 255, 0, 0, 0, 255, 1, 2, 3],
+            &ppm[header.len()..]
+        );
+        let wide: V2<[u8; 3], 1, 3> = V2::default();
+        assert!(wide.to_ppm().starts_with(b"P6\n3 1\n255\n"));
+    }
+    #[test]
+    fn test_into_iter() {
+        let v: V2<String, 2, 2> = V2::new(["a", "b", "c", "d"].map(String::from).to_vec()).unwrap();
+        let mut joined = String::new();
+        for s in &v {
+            joined.push_str(s);
+        }
+        assert_eq!("abcd", joined);
+        let owned: Vec<String> = v.into_iter().collect();
+        assert_eq!(vec!["a", "b", "c", "d"], owned);
+    }
+    #[test]
+    fn test_from_ppm() {
+        let v: V2<[u8; 3], 2, 3> =
+            V2::new((0..6).map(|i| [i * 40, 255 - i * 40, i]).collect()).unwrap();
+        assert_eq!(v, V2::from_ppm(&v.to_ppm()).unwrap());
+        let commented = b"P6 # a comment\n3 2\n255\n"
+            .iter()
+            .chain(v.to_ppm()[11..].iter())
+            .copied()
+            .collect::<Vec<u8>>();
+        assert_eq!(v, V2::from_ppm(&commented).unwrap());
+        assert!(matches!(
+            V2::<[u8; 3], 3, 2>::from_ppm(&v.to_ppm()),
+            Err(VError::SizingError {
+                expected: 2,
+                actual: 3
+            })
+        ));
+        let ppm = v.to_ppm();
+        assert!(matches!(
+            V2::<[u8; 3], 2, 3>::from_ppm(&ppm[..ppm.len() - 1]),
+            Err(VError::SizingError {
+                expected: 18,
+                actual: 17
+            })
+        ));
+        assert!(matches!(
+            V2::<[u8; 3], 2, 3>::from_ppm(b"P3\n3 2\n255\n"),
+            Err(VError::ParseError(_))
+        ));
+    }
+    #[test]
+    fn test_from_fn() {
+        let v: V2<usize, 3, 2> = V2::from_fn(|ix| (ix.y() + 1) * (ix.x() + 1));
+        assert_eq!(Some(&1), v.get(BoundedIx2::new(0, 0)));
+        assert_eq!(Some(&4), v.get(BoundedIx2::new(1, 1)));
+        assert_eq!(Some(&3), v.get(BoundedIx2::new(2, 0)));
+        assert_eq!(Some(&6), v.get(BoundedIx2::new(2, 1)));
+        assert_eq!(vec![1, 2, 2, 4, 3, 6], v.data);
+    }
+    #[test]
+    fn test_set_all() {
+        let mut v: V2<u8, 2, 2> = V2::default();
+        let corner = BoundedIx2::new(0, 0).unwrap();
+        v.set_all([
+            (Some(corner), 1),
+            (corner.east(), 2),
+            (corner.southeast(), 3),
+        ])
+        .unwrap();
+        assert_eq!(vec![1, 2, 0, 3], v.data);
+        assert!(matches!(
+            v.set_all([(corner.south(), 9), (corner.north(), 9)]),
+            Err(VError::IndexError)
+        ));
+        assert_eq!(vec![1, 2, 0, 3], v.data);
+    }
+    #[test]
+    fn test_from_rows() {
+        let v = V2::<u8, 2, 3>::from_rows([[0, 1, 2], [3, 4, 5]]);
+        assert_eq!(V2::<u8, 2, 3>::new((0..6).collect()).unwrap(), v);
+    }
+    #[test]
+    fn test_try_from_rows() {
+        let v = V2::<u8, 2, 3>::try_from(vec![vec![0, 1, 2], vec![3, 4, 5]]).unwrap();
+        assert_eq!(vec![0, 1, 2, 3, 4, 5], v.data);
+        assert!(matches!(
+            V2::<u8, 2, 3>::try_from(vec![vec![0, 1, 2]]),
+            Err(VError::SizingError {
+                expected: 2,
+                actual: 1
+            })
+        ));
+        assert!(matches!(
+            V2::<u8, 2, 3>::try_from(vec![vec![0, 1, 2], vec![3, 4]]),
+            Err(VError::SizingError {
+                expected: 3,
+                actual: 2
+            })
+        ));
+    }
+    #[test]
+    fn test_validate() {
+        let v: V2<char, 2, 3> = V2::from_rows([['.', '#', '.'], ['#', '.', '.']]);
+        assert_eq!(Ok(()), v.validate(|c| ".#".contains(*c)));
+        let v: V2<char, 2, 3> = V2::from_rows([['.', 'x', '.'], ['#', '.', '?']]);
+        assert_eq!(
+            Err(vec![
+                BoundedIx2::new(0, 1).unwrap(),
+                BoundedIx2::new(1, 2).unwrap()
+            ]),
+            v.validate(|c| ".#".contains(*c))
+        );
+    }
+    #[test]
+    fn test_as_slice() {
+        let mut v: V2<u8, 2, 3> = V2::new((0..6).collect()).unwrap();
+        assert_eq!(&[0, 1, 2, 3, 4, 5], v.as_slice());
+        v.as_mut_slice()[4] = 9;
+        assert_eq!(9, v[BoundedIx2::new(1, 1).unwrap()]);
+        let round_trip: V2<u8, 2, 3> = V2::new(v.as_slice().to_vec()).unwrap();
+        assert_eq!(v, round_trip);
+        assert_eq!(vec![0, 1, 2, 3, 9, 5], v.into_inner());
+    }
+    #[test]
+    fn test_astar_diagonal() {
+        let open: V2<char, 3, 3> =
+            V2::from_rows([['.', '.', '.'], ['.', '.', '.'], ['.', '.', '.']]);
+        let start = BoundedIx2::new(0, 0).unwrap();
+        let goal = BoundedIx2::new(2, 2).unwrap();
+        let (cost, path) = open.astar_diagonal(start, goal, |c| *c == '.').unwrap();
+        assert!((cost - 2.0 * std::f64::consts::SQRT_2).abs() < 1e-9);
+        assert_eq!(vec![start, BoundedIx2::new(1, 1).unwrap(), goal], path);
+        let walled: V2<char, 2, 2> = V2::from_rows([['.', '#'], ['#', '.']]);
+        let start = BoundedIx2::new(0, 0).unwrap();
+        let goal = BoundedIx2::new(1, 1).unwrap();
+        assert_eq!(None, walled.astar_diagonal(start, goal, |c| *c == '.'));
+        let half_walled: V2<char, 2, 2> = V2::from_rows([['.', '#'], ['.', '.']]);
+        let (cost, path) = half_walled
+            .astar_diagonal(start, goal, |c| *c == '.')
+            .unwrap();
+        assert!((cost - std::f64::consts::SQRT_2).abs() < 1e-9);
+        assert_eq!(vec![start, goal], path);
+    }
+    #[test]
+    fn test_convolve() {
+        let v: V2<u8, 3, 3> = V2::new((1..=9).collect()).unwrap();
+        let blur: V2<f64, 3, 3> = V2::new(vec![1.0 / 9.0; 9]).unwrap();
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+        let center = BoundedIx2::new(1, 1).unwrap();
+        let corner = BoundedIx2::new(0, 0).unwrap();
+        let zero = v.convolve(&blur, EdgeMode::Zero);
+        assert!(close(5.0, zero[center]));
+        assert!(close(12.0 / 9.0, zero[corner]));
+        let clamp = v.convolve(&blur, EdgeMode::Clamp);
+        assert!(close(5.0, clamp[center]));
+        assert!(close(21.0 / 9.0, clamp[corner]));
+        let wrap = v.convolve(&blur, EdgeMode::Wrap);
+        assert!(wrap.iter().all(|x| close(5.0, *x)));
+    }
+    #[test]
+    fn test_dimensions() {
+        let v: V2<u8, 3, 4> = V2::default();
+        assert_eq!(3, v.n_rows());
+        assert_eq!(4, v.n_cols());
+        assert_eq!(12, v.len());
+        assert!(!v.is_empty());
+        let empty: V2<u8, 0, 4> = V2::default();
+        assert!(empty.is_empty());
+    }
+    #[test]
+    fn test_longest_increasing_path() {
+        let v: V2<u8, 3, 3> = V2::from_rows([[9, 9, 4], [6, 6, 8], [2, 1, 1]]);
+        assert_eq!(4, v.longest_increasing_path());
+        let v: V2<u8, 3, 3> = V2::from_rows([[3, 4, 5], [3, 2, 6], [2, 2, 1]]);
+        assert_eq!(4, v.longest_increasing_path());
+        let v: V2<u8, 2, 2> = V2::from_rows([[7, 7], [7, 7]]);
+        assert_eq!(1, v.longest_increasing_path());
+    }
+    #[test]
+    fn test_swap() {
+        let mut v: V2<u8, 2, 2> = V2::from_rows([[0, 1], [2, 3]]);
+        v.swap(BoundedIx2::new(0, 0), BoundedIx2::new(1, 1))
+            .unwrap();
+        assert_eq!(V2::from_rows([[3, 1], [2, 0]]), v);
+        v.swap(BoundedIx2::new(0, 1), BoundedIx2::new(0, 1))
+            .unwrap();
+        assert_eq!(V2::from_rows([[3, 1], [2, 0]]), v);
+        assert!(matches!(
+            v.swap(BoundedIx2::new(0, 0), BoundedIx2::new(2, 0)),
+            Err(VError::IndexError)
+        ));
+        assert_eq!(V2::from_rows([[3, 1], [2, 0]]), v);
+    }
+    #[test]
+    fn test_snapshot() {
+        let mut v: V2<u32, 3, 3> = V2::new((0..9).collect()).unwrap();
+        let snapshot = v.snapshot();
+        v[BoundedIx2::new(0, 0).unwrap()] = 100;
+        let sums: Vec<u32> = std::thread::scope(|s| {
+            (0..4)
+                .map(|_| {
+                    let snapshot = Arc::clone(&snapshot);
+                    s.spawn(move || snapshot.iter().sum())
+                })
+                .collect::<Vec<_>>()
+                .into_iter()
+                .map(|h| h.join().unwrap())
+                .collect()
+        });
+        assert_eq!(vec![36; 4], sums);
+    }
+    #[test]
+    fn test_fill() {
+        let mut v: V2<u8, 2, 2> = V2::from_rows([[0, 1], [2, 3]]);
+        v.fill(7);
+        assert_eq!(V2::from_rows([[7, 7], [7, 7]]), v);
+        let mut counter = 0;
+        v.fill_with(|| {
+            counter += 1;
+            counter
+        });
+        assert_eq!(V2::from_rows([[1, 2], [3, 4]]), v);
+    }
+    #[test]
+    fn test_region_corners() {
+        let v: V2<char, 3, 3> = V2::from_rows([['A', 'A', 'B'], ['A', 'B', 'B'], ['B', 'B', 'B']]);
+        let corners = v.region_corners(|c| *c as usize);
+        assert_eq!(Some(&6), corners.get(&('A' as usize)));
+        assert_eq!(Some(&8), corners.get(&('B' as usize)));
+        let v: V2<char, 3, 4> = V2::from_rows([
+            ['.', '.', '.', '.'],
+            ['.', 'R', 'R', '.'],
+            ['.', '.', '.', '.'],
+        ]);
+        let corners = v.region_corners(|c| *c as usize);
+        assert_eq!(Some(&4), corners.get(&('R' as usize)));
+        assert_eq!(Some(&8), corners.get(&('.' as usize)));
+    }
+    #[test]
+    fn test_get_row_col() {
+        let v: V2<u8, 3, 3> = V2::new((0..9).collect()).unwrap();
+        assert_eq!(Some(&[3, 4, 5][..]), v.get_row(1));
+        assert_eq!(Some(vec![&2, &5, &8]), v.get_col(2));
+        assert_eq!(None, v.get_row(3));
+        assert_eq!(None, v.get_col(3));
+    }
+    #[test]
+    fn test_transpose_into() {
+        let v: V2<u8, 2, 3> = V2::new((0..6).collect()).unwrap();
+        let mut out: V2<u8, 3, 2> = V2::new(vec![99; 6]).unwrap();
+        v.transpose_into(&mut out);
+        assert_eq!(v.transpose(), out);
+        assert!(out.iter().all(|x| *x != 99));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let v: V2<u8, 3, 3> = V2::new((0..9).collect()).unwrap();
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(r#"{"data":[0,1,2,3,4,5,6,7,8]}"#, json);
+        let round_trip: V2<u8, 3, 3> = serde_json::from_str(&json).unwrap();
+        assert_eq!(v, round_trip);
+        assert!(serde_json::from_str::<V2<u8, 3, 3>>(r#"{"data":[0,1,2]}"#).is_err());
+    }
+    #[test]
+    fn test_unique_wrapping_neighbors() {
+        let v: V2<u8, 2, 3> = V2::new((0..6).collect()).unwrap();
+        let ix = BoundedIx2::new(0, 1).unwrap();
+        let neighbors: Vec<(BoundedIx2<2, 3>, &u8)> = v.unique_wrapping_neighbors(ix).collect();
+        assert_eq!(5, neighbors.len());
+        assert_eq!(
+            vec![&3, &4, &5, &0, &2],
+            neighbors.iter().map(|(_, x)| *x).collect::<Vec<_>>()
+        );
+        let v: V2<u8, 1, 1> = V2::new(vec![0]).unwrap();
+        assert_eq!(0, v.unique_wrapping_neighbors(BoundedIx2::min()).count());
+    }
+    #[test]
+    fn test_hash() {
+        use std::collections::HashSet;
+        let mut set = HashSet::new();
+        assert!(set.insert(V2::<u8, 2, 2>::from_rows([[0, 1], [2, 3]])));
+        assert!(set.insert(V2::<u8, 2, 2>::from_rows([[3, 2], [1, 0]])));
+        assert!(!set.insert(V2::<u8, 2, 2>::from_rows([[0, 1], [2, 3]])));
+        assert_eq!(2, set.len());
+    }
+    #[test]
+    fn test_run_life() {
+        let horizontal: V2<bool, 3, 3> = V2::from_fn(|ix| ix.y() == 1);
+        let vertical: V2<bool, 3, 3> = V2::from_fn(|ix| ix.x() == 1);
+        assert_eq!(vertical, horizontal.run_life(1));
+        assert_eq!(horizontal, horizontal.run_life(2));
+        assert_eq!(vertical, horizontal.run_life(5));
+        let block: V2<bool, 4, 4> =
+            V2::from_fn(|ix| (1..3).contains(&ix.x()) && (1..3).contains(&ix.y()));
+        assert_eq!(block, block.run_life_until_stable(100));
+        assert_eq!(
+            horizontal.run_life(10),
+            horizontal.run_life_until_stable(10)
+        );
+    }
+    #[test]
+    fn test_wrapping_accessors() {
+        let v: V2<u8, 3, 3> = V2::new((0..9).collect()).unwrap();
+        let top_left = BoundedIx2::new(0, 0).unwrap();
+        let bottom_right = BoundedIx2::new(2, 2).unwrap();
+        let top_middle = BoundedIx2::new(0, 1).unwrap();
+        assert_eq!(&7, v.north_wrapping(top_middle));
+        assert_eq!(&1, v.south_wrapping(BoundedIx2::new(2, 1).unwrap()));
+        assert_eq!(&3, v.east_wrapping(BoundedIx2::new(1, 2).unwrap()));
+        assert_eq!(&5, v.west_wrapping(BoundedIx2::new(1, 0).unwrap()));
+        assert_eq!(&8, v.northwest_wrapping(top_left));
+        assert_eq!(&7, v.northeast_wrapping(top_left));
+        assert_eq!(&5, v.southwest_wrapping(top_left));
+        assert_eq!(&4, v.southeast_wrapping(top_left));
+        assert_eq!(&0, v.southeast_wrapping(bottom_right));
+        assert_eq!(
+            vec![&8, &6, &7, &2, &1, &5, &3, &4],
+            v.neighbors_wrapping_of(top_left).collect::<Vec<_>>()
+        );
+    }
+    #[test]
+    fn test_get_rc() {
+        let v: V2<u8, 2, 3> = V2::new((0..6).collect()).unwrap();
+        assert_eq!(Some(&5), v.get_rc(Row(1), Col(2)));
+        assert_eq!(Some(&1), v.get_rc(Row(0), Col(1)));
+        assert_eq!(None, v.get_rc(Row(2), Col(1)));
+    }
+    #[test]
+    fn test_get_clamped() {
+        let v: V2<u8, 3, 3> = V2::new((0..9).collect()).unwrap();
+        assert_eq!(&1, v.get_clamped(-5, 1));
+        assert_eq!(&8, v.get_clamped(10, 10));
+        assert_eq!(&3, v.get_clamped(1, -1));
+        assert_eq!(&4, v.get_clamped(1, 1));
+    }
+    #[test]
+    fn test_moore_neighborhood() {
+        let v: V2<u8, 5, 5> = V2::new((0..25).collect()).unwrap();
+        for ix in V2Indices::<5, 5>::new() {
+            let expected: Vec<&u8> = Ix2Neighbors::new(ix).map(|n| &v[n]).collect();
+            assert_eq!(expected, v.moore_neighborhood(ix, 1).collect::<Vec<_>>());
+        }
+        let center = BoundedIx2::new(2, 2).unwrap();
+        assert_eq!(24, v.moore_neighborhood(center, 2).count());
+        let corner = BoundedIx2::new(0, 0).unwrap();
+        assert_eq!(
+            vec![&1, &2, &5, &6, &7, &10, &11, &12],
+            v.moore_neighborhood(corner, 2).collect::<Vec<_>>()
+        );
+        assert_eq!(24, v.moore_neighborhood(corner, 10).count());
+        assert_eq!(24, v.moore_neighborhood(center, usize::MAX).count());
+    }
+    #[test]
+    fn test_pad_sides() {
+        let v: V2<u8, 2, 2> = V2::from_rows([[1, 2], [3, 4]]);
+        let padded: V2<u8, 5, 5> = v.pad_sides::<1, 2, 0, 3>(0);
+        let expected = V2::from_rows([
+            [0, 0, 0, 0, 0],
+            [1, 2, 0, 0, 0],
+            [3, 4, 0, 0, 0],
+            [0, 0, 0, 0, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        assert_eq!(expected, padded);
+        let padded: V2<u8, 2, 4> = v.pad_sides::<0, 0, 2, 0>(9);
+        assert_eq!(V2::from_rows([[9, 9, 1, 2], [9, 9, 3, 4]]), padded);
+    }
+    #[test]
+    fn test_local_extrema() {
+        let v: V2<u8, 3, 5> = V2::from_rows([[2, 1, 9, 9, 9], [3, 9, 8, 7, 8], [9, 8, 5, 6, 7]]);
+        assert_eq!(
+            vec![
+                BoundedIx2::new(0, 1).unwrap(),
+                BoundedIx2::new(2, 2).unwrap()
+            ],
+            v.local_minima()
+        );
+        assert_eq!(
+            vec![
+                BoundedIx2::new(1, 1).unwrap(),
+                BoundedIx2::new(2, 0).unwrap()
+            ],
+            v.local_maxima()
+        );
+        let v: V2<u8, 2, 2> = V2::from_rows([[1, 2], [2, 1]]);
+        assert_eq!(
+            vec![
+                BoundedIx2::new(0, 1).unwrap(),
+                BoundedIx2::new(1, 0).unwrap()
+            ],
+            v.local_maxima()
+        );
+    }
+    #[test]
+    fn test_von_neumann_neighborhood() {
+        let v: V2<u8, 5, 5> = V2::new((0..25).collect()).unwrap();
+        for ix in V2Indices::<5, 5>::new() {
+            let mut expected: Vec<&u8> = Ix2CardinalNeighbors::new(ix).map(|n| &v[n]).collect();
+            expected.sort();
+            assert_eq!(
+                expected,
+                v.von_neumann_neighborhood(ix, 1).collect::<Vec<_>>()
+            );
+        }
+        let center = BoundedIx2::new(2, 2).unwrap();
+        assert_eq!(
+            vec![&2, &6, &7, &8, &10, &11, &13, &14, &16, &17, &18, &22],
+            v.von_neumann_neighborhood(center, 2).collect::<Vec<_>>()
+        );
+        assert_eq!(24, v.von_neumann_neighborhood(center, 10).count());
+        assert_eq!(
+            24,
+            v.von_neumann_neighborhood(BoundedIx2::min(), usize::MAX)
+                .count()
+        );
+    }
+    #[test]
+    fn test_sum_risk() {
+        let v: V2<u8, 5, 10> = V2::from_rows([
+            [2, 1, 9, 9, 9, 4, 3, 2, 1, 0],
+            [3, 9, 8, 7, 8, 9, 4, 9, 2, 1],
+            [9, 8, 5, 6, 7, 8, 9, 8, 9, 2],
+            [8, 7, 6, 7, 8, 9, 6, 7, 8, 9],
+            [9, 8, 9, 9, 9, 6, 5, 6, 7, 8],
+        ]);
+        assert_eq!(15, v.sum_risk());
+    }
+    #[test]
+    fn test_basin_sizes() {
+        let v: V2<u8, 3, 5> = V2::from_rows([[1, 2, 9, 3, 4], [2, 3, 9, 2, 3], [9, 9, 9, 1, 2]]);
+        assert_eq!(vec![6, 4], v.basin_sizes());
+    }
+    #[test]
+    fn test_vstack_all() {
+        let parts: [V2<u8, 1, 3>; 3] = [
+            V2::from_rows([[0, 1, 2]]),
+            V2::from_rows([[3, 4, 5]]),
+            V2::from_rows([[6, 7, 8]]),
+        ];
+        let stacked: V2<u8, 3, 3> = vstack_all(parts);
+        assert_eq!(V2::from_rows([[0, 1, 2], [3, 4, 5], [6, 7, 8]]), stacked);
+    }
+    #[test]
+    fn test_antidiagonal_order() {
+        let v: V2<u8, 2, 3> = V2::default();
+        let order: Vec<(usize, usize)> =
+            v.antidiagonal_order().map(|ix| (ix.y(), ix.x())).collect();
+        assert_eq!(vec![(0, 0), (0, 1), (1, 0), (0, 2), (1, 1), (1, 2)], order);
+        assert_eq!(0, V2::<u8, 3, 0>::default().antidiagonal_order().count());
+        for (i, group) in order.chunk_by(|a, b| a.0 + a.1 == b.0 + b.1).enumerate() {
+            assert!(group.iter().all(|(r, c)| r + c == i));
+        }
+    }
+    #[test]
+    fn test_flood_fill() {
+        let mut v: V2<u8, 5, 5> = V2::from_rows([
+            [0, 0, 1, 0, 0],
+            [0, 0, 1, 0, 0],
+            [1, 1, 1, 0, 0],
+            [0, 1, 0, 0, 0],
+            [0, 1, 0, 0, 0],
+        ]);
+        v.flood_fill(BoundedIx2::new(0, 0).unwrap(), 2);
+        let expected = V2::from_rows([
+            [2, 2, 1, 0, 0],
+            [2, 2, 1, 0, 0],
+            [1, 1, 1, 0, 0],
+            [0, 1, 0, 0, 0],
+            [0, 1, 0, 0, 0],
+        ]);
+        assert_eq!(expected, v);
+        v.flood_fill(BoundedIx2::new(0, 4).unwrap(), 0);
+        assert_eq!(expected, v);
+    }
+    #[test]
+    fn test_edit_distance_table() {
+        let a: Vec<char> = "kitten".chars().collect();
+        let b: Vec<char> = "sitting".chars().collect();
+        let table = edit_distance_table(&a, &b);
+        assert_eq!(7, table.len());
+        assert!(table.iter().all(|row| row.len() == 8));
+        assert_eq!(3, table[6][7]);
+        assert_eq!(vec![0, 1, 2, 3, 4, 5, 6, 7], table[0]);
+        assert_eq!(
+            vec![0, 1, 2, 3, 4, 5, 6],
+            table.iter().map(|row| row[0]).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![vec![0], vec![1], vec![2]],
+            edit_distance_table(&['a', 'b'], &[])
+        );
+    }
+    #[test]
+    fn test_region_of() {
+        let v: V2<bool, 3, 4> = V2::from_rows([
+            [true, true, false, true],
+            [false, true, false, true],
+            [false, false, false, true],
+        ]);
+        let ix = |r, c| BoundedIx2::new(r, c).unwrap();
+        assert_eq!(
+            vec![ix(0, 0), ix(0, 1), ix(1, 1)],
+            v.region_of(ix(0, 0), |b| *b)
+        );
+        assert_eq!(
+            vec![ix(1, 3), ix(0, 3), ix(2, 3)],
+            v.region_of(ix(1, 3), |b| *b)
+        );
+        assert!(v.region_of(ix(2, 0), |b| *b).is_empty());
+    }
+    #[test]
+    fn test_find_neighbor() {
+        let v: V2<u8, 3, 3> = V2::from_rows([[1, 2, 3], [4, 5, 9], [7, 8, 6]]);
+        let center = BoundedIx2::new(1, 1).unwrap();
+        let over_five = |ix: BoundedIx2<3, 3>, x: &u8| (*x > 5).then_some(ix);
+        assert_eq!(BoundedIx2::new(1, 2), v.find_neighbor(center, over_five));
+        assert_eq!(
+            Some(4),
+            v.find_neighbor(BoundedIx2::min(), |_, x| (*x > 3).then_some(*x))
+        );
+        assert_eq!(
+            None,
+            v.find_neighbor(BoundedIx2::min(), |_, x| (*x > 5).then_some(*x))
+        );
+    }
+    #[test]
+    fn test_bfs_distances() {
+        let v: V2<char, 3, 4> = V2::from_rows([
+            ['.', '.', '#', '.'],
+            ['#', '.', '#', '.'],
+            ['.', '.', '#', '.'],
+        ]);
+        let distances = v.bfs_distances(BoundedIx2::min(), |c| *c == '.');
+        let expected = V2::from_rows([
+            [Some(0), Some(1), None, None],
+            [None, Some(2), None, None],
+            [Some(4), Some(3), None, None],
+        ]);
+        assert_eq!(expected, distances);
+    }
+    #[test]
+    fn test_astar() {
+        let v: V2<char, 4, 4> = V2::from_rows([
+            ['.', '.', '.', '.'],
+            ['#', '#', '#', '.'],
+            ['.', '.', '.', '.'],
+            ['.', '#', '#', '#'],
+        ]);
+        let start = BoundedIx2::new(0, 0).unwrap();
+        let goal = BoundedIx2::new(3, 0).unwrap();
+        let manhattan =
+            |ix: BoundedIx2<4, 4>| (ix.x().abs_diff(goal.x()) + ix.y().abs_diff(goal.y())) as u32;
+        let path = v
+            .astar(start, goal, |c| (*c == '.').then_some(1), manhattan)
+            .unwrap();
+        assert_eq!(10, path.len());
+        assert_eq!(Some(&start), path.first());
+        assert_eq!(Some(&goal), path.last());
+        assert!(path.iter().all(|ix| v[*ix] == '.'));
+        assert!(
+            path.windows(2)
+                .all(|w| Ix2CardinalNeighbors::new(w[0]).any(|n| n == w[1]))
+        );
+        let blocked = BoundedIx2::new(3, 3).unwrap();
+        assert_eq!(
+            None,
+            v.astar(start, blocked, |c| (*c == '.').then_some(1), |_| 0)
+        );
+        let expensive: V2<u32, 1, 3> = V2::from_rows([[0, u32::MAX, u32::MAX]]);
+        let (start, middle, end) = (
+            BoundedIx2::min(),
+            BoundedIx2::new(0, 1).unwrap(),
+            BoundedIx2::max(),
+        );
+        assert_eq!(
+            Some(vec![start, middle]),
+            expensive.astar(start, middle, |x| Some(*x), |_| u32::MAX)
+        );
+        assert_eq!(None, expensive.astar(start, end, |x| Some(*x), |_| 0));
+    }
+    #[test]
+    fn test_csv() {
+        let v: V2<i32, 2, 3> = V2::from_rows([[1, -2, 3], [40, 5, -60]]);
+        let csv = v.to_csv();
+        assert_eq!("1,-2,3\n40,5,-60\n", csv);
+        assert_eq!(v, V2::from_csv(&csv).unwrap());
+        assert!(matches!(
+            V2::<i32, 2, 3>::from_csv("1,2,3\n4,5\n"),
+            Err(VError::SizingError {
+                expected: 3,
+                actual: 2
+            })
+        ));
+        assert!(matches!(
+            V2::<i32, 2, 3>::from_csv("1,2,3\n4,x,6\n"),
+            Err(VError::ParseError(_))
+        ));
+        assert_eq!(v, V2::from_csv("1,-2,3\n40,5,-60\n\n\r\n").unwrap());
+    }
+    #[test]
+    fn test_region_count_8() {
+        let v: V2<bool, 3, 3> = V2::from_rows([
+            [true, false, false],
+            [false, true, false],
+            [false, false, false],
+        ]);
+        assert_eq!(1, v.region_count_8(|b| *b));
+        assert_eq!(2, v.label_components(|b| *b, false).1);
+        let v: V2<bool, 3, 3> = V2::from_rows([
+            [true, false, true],
+            [false, false, false],
+            [true, true, false],
+        ]);
+        assert_eq!(3, v.region_count_8(|b| *b));
+    }
+    #[test]
+    fn test_label_components() {
+        let v: V2<u8, 4, 4> =
+            V2::from_rows([[1, 1, 0, 0], [0, 0, 1, 0], [0, 0, 0, 0], [1, 0, 0, 1]]);
+        let (labels, count) = v.label_components(|x| *x == 1, false);
+        assert_eq!(4, count);
+        let expected = V2::from_rows([
+            [Some(0), Some(0), None, None],
+            [None, None, Some(1), None],
+            [None, None, None, None],
+            [Some(2), None, None, Some(3)],
+        ]);
+        assert_eq!(expected, labels);
+        let (labels, count) = v.label_components(|x| *x == 1, true);
+        assert_eq!(3, count);
+        assert_eq!(Some(0), labels[BoundedIx2::new(1, 2).unwrap()]);
+        assert_eq!(Some(2), labels[BoundedIx2::new(3, 3).unwrap()]);
+    }
+    #[test]
+    fn test_rotate_ring() {
+        let mut v: V2<u8, 3, 3> = V2::new((0..9).collect()).unwrap();
+        v.rotate_ring(0, 1);
+        assert_eq!(V2::from_rows([[3, 0, 1], [6, 4, 2], [7, 8, 5]]), v);
+        v.rotate_ring(0, -1);
+        assert_eq!(V2::new((0..9).collect::<Vec<u8>>()).unwrap(), v);
+        v.rotate_ring(1, 3);
+        v.rotate_ring(2, 1);
+        assert_eq!(V2::new((0..9).collect::<Vec<u8>>()).unwrap(), v);
+        let mut v: V2<u8, 4, 4> = V2::new((0..16).collect()).unwrap();
+        v.rotate_ring(1, 1);
+        let expected =
+            V2::from_rows([[0, 1, 2, 3], [4, 9, 5, 7], [8, 10, 6, 11], [12, 13, 14, 15]]);
+        assert_eq!(expected, v);
+    }
+    #[test]
+    fn test_map_with_neighbors() {
+        let life = |alive: &bool, neighbors: &[&bool]| {
+            let live_neighbors = neighbors.iter().filter(|n| ***n).count();
+            matches!((alive, live_neighbors), (true, 2) | (_, 3))
+        };
+        let horizontal: V2<bool, 3, 3> = V2::from_fn(|ix| ix.y() == 1);
+        let vertical: V2<bool, 3, 3> = V2::from_fn(|ix| ix.x() == 1);
+        let next = horizontal.map_with_neighbors(life);
+        assert_eq!(vertical, next);
+        assert_eq!(horizontal, next.map_with_neighbors(life));
+        assert_eq!(horizontal, V2::from_fn(|ix| ix.y() == 1));
+    }
+    #[test]
+    fn test_max_run() {
+        let v: V2<char, 4, 5> = V2::from_rows([
+            ['x', 'x', '.', 'x', 'x'],
+            ['.', 'x', 'x', 'x', '.'],
+            ['.', 'x', '.', 'x', '.'],
+            ['.', 'x', '.', '.', 'x'],
+        ]);
+        assert_eq!(3, v.max_run(&'x', Direction::East));
+        assert_eq!(3, v.max_run(&'x', Direction::West));
+        assert_eq!(4, v.max_run(&'x', Direction::South));
+        assert_eq!(4, v.max_run(&'x', Direction::SouthEast));
+        assert_eq!(0, v.max_run(&'o', Direction::North));
+    }
+    #[test]
+    fn test_has_line_of() {
+        let diagonal: V2<char, 3, 3> =
+            V2::from_rows([['o', 'x', 'x'], ['.', 'x', 'o'], ['x', 'o', '.']]);
+        assert!(diagonal.has_line_of(&'x', 3));
+        assert!(diagonal.has_line_of(&'o', 2));
+        assert!(!diagonal.has_line_of(&'o', 3));
+        let horizontal: V2<char, 3, 3> =
+            V2::from_rows([['.', 'o', '.'], ['x', 'x', 'x'], ['o', '.', 'o']]);
+        assert!(horizontal.has_line_of(&'x', 3));
+        let near_miss: V2<char, 3, 3> =
+            V2::from_rows([['x', 'x', 'o'], ['o', 'o', 'x'], ['x', 'x', 'o']]);
+        assert!(!near_miss.has_line_of(&'x', 3));
+        assert!(near_miss.has_line_of(&'o', 2));
+    }
+    #[test]
+    fn test_diagonal() {
+        let v: V2<u8, 3, 3> = V2::new((0..9).collect()).unwrap();
+        assert_eq!(vec![&0, &4, &8], v.diagonal().collect::<Vec<_>>());
+        assert_eq!(12, v.trace());
+        let v: V2<u8, 2, 4> = V2::new((0..8).collect()).unwrap();
+        assert_eq!(vec![&0, &5], v.diagonal().collect::<Vec<_>>());
+        assert_eq!(5, v.trace());
+    }
+    #[test]
+    fn test_custom_neighbors() {
+        let v: V2<u8, 5, 5> = V2::new((0..25).collect()).unwrap();
+        let cardinal = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+        for ix in V2Indices::<5, 5>::new() {
+            let expected: Vec<BoundedIx2<5, 5>> = Ix2CardinalNeighbors::new(ix).collect();
+            let actual: Vec<BoundedIx2<5, 5>> =
+                v.custom_neighbors(ix, &cardinal).map(|(n, _)| n).collect();
+            assert_eq!(expected, actual);
+        }
+        let knight = [
+            (-2, -1),
+            (-2, 1),
+            (-1, -2),
+            (-1, 2),
+            (1, -2),
+            (1, 2),
+            (2, -1),
+            (2, 1),
+        ];
+        let center = BoundedIx2::new(2, 2).unwrap();
+        assert_eq!(
+            vec![&1, &3, &5, &9, &15, &19, &21, &23],
+            v.custom_neighbors(center, &knight)
+                .map(|(_, x)| x)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![&7, &11],
+            v.custom_neighbors(BoundedIx2::min(), &knight)
+                .map(|(_, x)| x)
+                .collect::<Vec<_>>()
+        );
+    }
+    #[test]
+    fn test_anti_diagonals() {
+        let v: V2<u8, 3, 3> = V2::new((0..9).collect()).unwrap();
+        let groups: Vec<Vec<(usize, usize, u8)>> = v
+            .anti_diagonals()
+            .map(|group| {
+                group
+                    .into_iter()
+                    .map(|(ix, x)| (ix.y(), ix.x(), *x))
+                    .collect()
+            })
+            .collect();
+        let expected = vec![
+            vec![(0, 0, 0)],
+            vec![(0, 1, 1), (1, 0, 3)],
+            vec![(0, 2, 2), (1, 1, 4), (2, 0, 6)],
+            vec![(1, 2, 5), (2, 1, 7)],
+            vec![(2, 2, 8)],
+        ];
+        assert_eq!(expected, groups);
+    }
+    #[test]
+    fn test_spiral() {
+        let v: V2<u8, 3, 3> = V2::new((0..9).collect()).unwrap();
+        assert_eq!(
+            vec![0, 1, 2, 5, 8, 7, 6, 3, 4],
+            v.spiral().copied().collect::<Vec<_>>()
+        );
+        let v: V2<u8, 2, 4> = V2::new((0..8).collect()).unwrap();
+        assert_eq!(
+            vec![0, 1, 2, 3, 7, 6, 5, 4],
+            v.spiral().copied().collect::<Vec<_>>()
+        );
+        let v: V2<u8, 4, 3> = V2::new((0..12).collect()).unwrap();
+        assert_eq!(
+            vec![0, 1, 2, 5, 8, 11, 10, 9, 6, 3, 4, 7],
+            v.spiral().copied().collect::<Vec<_>>()
+        );
+        let v: V2<u8, 1, 3> = V2::new((0..3).collect()).unwrap();
+        assert_eq!(vec![0, 1, 2], v.spiral().copied().collect::<Vec<_>>());
+        let v: V2<u8, 3, 1> = V2::new((0..3).collect()).unwrap();
+        assert_eq!(vec![0, 1, 2], v.spiral().copied().collect::<Vec<_>>());
+    }
+    #[test]
+    fn test_border() {
+        let v: V2<u8, 3, 3> = V2::new((0..9).collect()).unwrap();
+        let border: Vec<(BoundedIx2<3, 3>, &u8)> = v.border().collect();
+        assert_eq!(8, border.len());
+        assert_eq!(
+            vec![&0, &1, &2, &5, &8, &7, &6, &3],
+            border.iter().map(|(_, x)| *x).collect::<Vec<_>>()
+        );
+        let v: V2<u8, 1, 4> = V2::new((0..4).collect()).unwrap();
+        assert_eq!(
+            vec![&0, &1, &2, &3],
+            v.border().map(|(_, x)| x).collect::<Vec<_>>()
+        );
+    }
+    #[test]
+    fn test_interior() {
+        let v: V2<u8, 3, 3> = V2::new((0..9).collect()).unwrap();
+        assert_eq!(
+            vec![(BoundedIx2::new(1, 1).unwrap(), &4)],
+            v.interior().collect::<Vec<_>>()
+        );
+        let v: V2<u8, 4, 4> = V2::new((0..16).collect()).unwrap();
+        assert_eq!(
+            vec![&5, &6, &9, &10],
+            v.interior().map(|(_, x)| x).collect::<Vec<_>>()
+        );
+        let v: V2<u8, 2, 5> = V2::default();
+        assert_eq!(0, v.interior().count());
+    }
+    #[test]
+    fn test_windows() {
+        let v: V2<u8, 3, 3> = V2::new((0..9).collect()).unwrap();
+        let windows: Vec<Vec<u8>> = v
+            .windows::<2, 2>()
+            .map(|w| w.into_iter().copied().collect())
+            .collect();
+        let expected = vec![
+            vec![0, 1, 3, 4],
+            vec![1, 2, 4, 5],
+            vec![3, 4, 6, 7],
+            vec![4, 5, 7, 8],
+        ];
+        assert_eq!(expected, windows);
+        assert_eq!(3, v.windows::<1, 3>().count());
+        assert_eq!(0, v.windows::<4, 1>().count());
+    }
+    #[test]
+    fn test_submatrix() {
+        let v: V2<u8, 3, 3> = V2::new((0..9).collect()).unwrap();
+        let top_left = BoundedIx2::new(1, 1).unwrap();
+        assert_eq!(
+            Some(V2::from_rows([[4, 5], [7, 8]])),
+            v.submatrix::<2, 2>(top_left)
+        );
+        let top_left = BoundedIx2::new(0, 2).unwrap();
+        assert_eq!(None, v.submatrix::<2, 2>(top_left));
+        assert_eq!(
+            Some(V2::from_rows([[2], [5], [8]])),
+            v.submatrix::<3, 1>(top_left)
+        );
+    }
+    #[test]
+    fn test_hstack_vstack() {
+        let a: V2<u8, 2, 2> = V2::from_rows([[0, 1], [2, 3]]);
+        let b: V2<u8, 2, 3> = V2::from_rows([[4, 5, 6], [7, 8, 9]]);
+        let wide: V2<u8, 2, 5> = a.clone().hstack(b);
+        assert_eq!(V2::from_rows([[0, 1, 4, 5, 6], [2, 3, 7, 8, 9]]), wide);
+        let c: V2<u8, 3, 2> = V2::from_rows([[4, 5], [6, 7], [8, 9]]);
+        let tall: V2<u8, 5, 2> = a.vstack(c);
+        assert_eq!(
+            V2::from_rows([[0, 1], [2, 3], [4, 5], [6, 7], [8, 9]]),
+            tall
+        );
+    }
+    #[test]
+    fn test_reshape() {
+        let v: V2<u8, 2, 6> = V2::new((0..12).collect()).unwrap();
+        let three_by_four: V2<u8, 3, 4> = v.clone().reshape();
+        assert_eq!(v.as_slice(), three_by_four.as_slice());
+        assert_eq!(6, three_by_four[BoundedIx2::new(1, 2).unwrap()]);
+        let four_by_three: V2<u8, 4, 3> = v.reshape();
+        assert_eq!(7, four_by_three[BoundedIx2::new(2, 1).unwrap()]);
+    }
+    #[test]
+    fn test_remove_row_col() {
+        let v: V2<u8, 3, 3> = V2::new((0..9).collect()).unwrap();
+        let without_row: V2<u8, 2, 3> = v.clone().remove_row(1).unwrap();
+        assert_eq!(V2::from_rows([[0, 1, 2], [6, 7, 8]]), without_row);
+        let without_col: V2<u8, 3, 2> = v.clone().remove_col(1).unwrap();
+        assert_eq!(V2::from_rows([[0, 2], [3, 5], [6, 8]]), without_col);
+        assert!(matches!(v.clone().remove_row(3), Err(VError::IndexError)));
+        assert!(matches!(v.remove_col(5), Err(VError::IndexError)));
+    }
 }
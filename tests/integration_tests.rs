@@ -22,7 +22,7 @@ fn test_owned() {
             && let Some(nix) = ix.north()
         {
             let s = *v2[wix].0 + *v2[nix].0;
-            if s % 3 == 0 {
+            if s.is_multiple_of(3) {
                 *v2[ix].0 += 10;
             }
         }